@@ -0,0 +1,51 @@
+//! Measure the jitter of repeated audio block waits using `SpinSleeper::sleep` compared with
+//! `AudioSpinSleeper::sleep_block`.
+use spin_sleep::{AudioSpinSleeper, SpinSleeper};
+use std::time::{Duration, Instant};
+
+/// 128 samples at 44.1kHz.
+const SAMPLES: u32 = 128;
+const SAMPLE_RATE: u32 = 44_100;
+const BLOCKS: u32 = 10_000;
+
+fn main() {
+    if cfg!(debug_assertions) {
+        eprintln!("Should run with `--release`");
+        std::process::exit(1);
+    }
+
+    let audio = AudioSpinSleeper::from_block_samples(SAMPLES, SAMPLE_RATE);
+    let block = audio.block();
+
+    eprintln!("==> {block:?} blocks SpinSleeper::sleep");
+    let sleeper = SpinSleeper::default();
+    report(block, || {
+        sleeper.sleep(block);
+    });
+
+    eprintln!("==> {block:?} blocks AudioSpinSleeper::sleep_block");
+    report(block, || audio.sleep_block());
+}
+
+fn report(block: Duration, mut wait: impl FnMut()) {
+    let mut lateness = Vec::with_capacity(BLOCKS as _);
+    for _ in 0..BLOCKS {
+        let start = Instant::now();
+        wait();
+        lateness.push(start.elapsed().saturating_sub(block));
+    }
+    lateness.sort_unstable();
+
+    let mean = lateness.iter().sum::<Duration>() / BLOCKS;
+    let variance = lateness
+        .iter()
+        .map(|l| (l.as_secs_f64() - mean.as_secs_f64()).powi(2))
+        .sum::<f64>()
+        / f64::from(BLOCKS);
+    println!(
+        "average lateness: {mean:.1?}, jitter (std dev): {:.1?}, p99: {:.1?}, worst: {:.1?}",
+        Duration::from_secs_f64(variance.sqrt()),
+        lateness[lateness.len() * 99 / 100],
+        lateness[lateness.len() - 1],
+    );
+}
@@ -0,0 +1,56 @@
+//! Measure high frequency `Interval::tick` lateness & overhead, comparing ticking that reads the
+//! clock twice per tick with the current single read implementation.
+use spin_sleep::SpinSleeper;
+use std::time::{Duration, Instant};
+
+const PERIOD: Duration = Duration::from_micros(50);
+const TICKS: u32 = 20_000;
+
+fn main() {
+    if cfg!(debug_assertions) {
+        eprintln!("Should run with `--release`");
+        std::process::exit(1);
+    }
+
+    let sleeper = SpinSleeper::default();
+
+    eprintln!(
+        "==> {:.0}Hz two clock reads per tick",
+        1.0 / PERIOD.as_secs_f64()
+    );
+    let mut next_tick = Instant::now() + PERIOD;
+    let (mut late_sum, mut worst) = (Duration::ZERO, Duration::ZERO);
+    let start = Instant::now();
+    for _ in 0..TICKS {
+        let now = Instant::now();
+        if now < next_tick {
+            // `SpinSleeper::sleep` reads the clock again
+            sleeper.sleep(next_tick - now);
+        }
+        let late = next_tick.elapsed();
+        late_sum += late;
+        worst = worst.max(late);
+        next_tick += PERIOD;
+    }
+    report(start.elapsed(), late_sum, worst);
+
+    eprintln!("==> {:.0}Hz Interval::tick", 1.0 / PERIOD.as_secs_f64());
+    let mut interval = spin_sleep_util::interval(PERIOD).with_spin_sleeper(sleeper);
+    let (mut late_sum, mut worst) = (Duration::ZERO, Duration::ZERO);
+    let start = Instant::now();
+    for _ in 0..TICKS {
+        let tick = interval.tick();
+        let late = tick.elapsed();
+        late_sum += late;
+        worst = worst.max(late);
+    }
+    report(start.elapsed(), late_sum, worst);
+}
+
+fn report(total: Duration, late_sum: Duration, worst: Duration) {
+    println!(
+        "average lateness: {:.1?}, worst lateness: {worst:.1?}, total: {total:.3?} (ideal {:.3?})",
+        late_sum / TICKS,
+        PERIOD * TICKS,
+    );
+}
@@ -1,5 +1,5 @@
 //! Call OS native sleep for **1ns, 1µs & 1ms** and see how long it actually takes.
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
 fn main() {
     if cfg!(debug_assertions) {
@@ -21,77 +21,18 @@ fn main() {
         std::thread::sleep(Duration::from_secs(1));
     }
 
-    eprintln!("==> sleep 1ns");
-
-    const ITS: u32 = 1000;
-
-    let mut best = Duration::MAX;
-    let mut sum = Duration::ZERO;
-    let mut worst = Duration::ZERO;
-
-    for _ in 0..ITS {
-        let before = Instant::now();
-        spin_sleep::native_sleep(Duration::from_nanos(1));
-        let elapsed = before.elapsed();
-        sum += elapsed;
-        if elapsed < best {
-            best = elapsed;
-        }
-        if elapsed > worst {
-            worst = elapsed;
-        }
-    }
-
-    println!(
-        "average: {:.1?}, best: {best:.1?}, worst: {worst:.1?}",
-        sum / ITS
-    );
-
-    eprintln!("==> sleep 1µs");
-
-    let mut best = Duration::MAX;
-    let mut sum = Duration::ZERO;
-    let mut worst = Duration::ZERO;
-
-    for _ in 0..ITS {
-        let before = Instant::now();
-        spin_sleep::native_sleep(Duration::from_micros(1));
-        let elapsed = before.elapsed();
-        sum += elapsed;
-        if elapsed < best {
-            best = elapsed;
-        }
-        if elapsed > worst {
-            worst = elapsed;
-        }
-    }
-
-    println!(
-        "average: {:.1?}, best: {best:.1?}, worst: {worst:.1?}",
-        sum / ITS
-    );
-
-    eprintln!("==> sleep 1ms");
-
-    let mut best = Duration::MAX;
-    let mut sum = Duration::ZERO;
-    let mut worst = Duration::ZERO;
-
-    for _ in 0..50 {
-        let before = Instant::now();
-        spin_sleep::native_sleep(Duration::from_millis(1));
-        let elapsed = before.elapsed();
-        sum += elapsed;
-        if elapsed < best {
-            best = elapsed;
-        }
-        if elapsed > worst {
-            worst = elapsed;
-        }
+    for (probe, samples) in [
+        (Duration::from_nanos(1), 1000),
+        (Duration::from_micros(1), 1000),
+        (Duration::from_millis(1), 50),
+    ] {
+        eprintln!("==> sleep {probe:?}");
+        let report = spin_sleep::measure_native_accuracy(samples, probe);
+        println!(
+            "average: {:.1?}, best: {:.1?}, worst: {:.1?}",
+            probe + report.mean,
+            probe + report.best,
+            probe + report.worst,
+        );
     }
-
-    println!(
-        "average: {:.3?}, best: {best:.3?}, worst: {worst:.3?}",
-        sum / 50
-    );
 }
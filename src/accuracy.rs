@@ -0,0 +1,226 @@
+use super::*;
+
+/// Broad class of achievable sleep accuracy on the current platform,
+/// see [`sleep_accuracy_class`].
+///
+/// Ordered from most to least accurate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AccuracyClass {
+    /// Native sleep & clock resolution support microsecond-level timing.
+    /// E.g. Windows high resolution timer, Linux.
+    Microsecond,
+    /// Native sleep or clock resolution is around a millisecond.
+    /// Precise timing requires spinning for up to ~1ms each sleep.
+    /// E.g. Windows `timeBeginPeriod(1)` fallback.
+    Millisecond,
+    /// Native sleep or clock resolution is worse than a couple of milliseconds.
+    /// Precise timing may not be feasible.
+    Coarse,
+}
+
+impl AccuracyClass {
+    /// Returns a short human readable description of this class,
+    /// e.g. to warn users on coarse setups.
+    pub fn describe(self) -> &'static str {
+        match self {
+            Self::Microsecond => "Microsecond — precise timing with minimal spinning",
+            Self::Millisecond => "Millisecond — precise timing requires ~1ms spinning per sleep",
+            Self::Coarse => "Coarse — precise timing may not be feasible",
+        }
+    }
+
+    /// Returns the class of the given native sleep or clock `resolution`.
+    fn of(resolution: Duration) -> Self {
+        match resolution {
+            r if r < Duration::from_micros(100) => Self::Microsecond,
+            r if r <= Duration::from_millis(2) => Self::Millisecond,
+            _ => Self::Coarse,
+        }
+    }
+}
+
+/// Returns the broad class of achievable sleep accuracy on the current platform.
+///
+/// Derived from detected platform capabilities: the least accurate of native sleep
+/// resolution, e.g. presence of the Windows high resolution timer, & [`clock_resolution`].
+///
+/// # Example
+/// ```
+/// use spin_sleep::{sleep_accuracy_class, AccuracyClass};
+///
+/// let class = sleep_accuracy_class();
+/// if class == AccuracyClass::Coarse {
+///     eprintln!("warning: {}", class.describe());
+/// }
+/// ```
+pub fn sleep_accuracy_class() -> AccuracyClass {
+    #[cfg(all(windows, not(miri)))]
+    let native = AccuracyClass::of(windows::native_sleep_resolution());
+    #[cfg(not(all(windows, not(miri))))]
+    let native = AccuracyClass::Microsecond;
+
+    native.max(AccuracyClass::of(clock_resolution()))
+}
+
+/// Returns `true` if [`native_sleep`] uses a high resolution timer.
+///
+/// On Windows this is `false` before Windows 10, version 1803, where native sleep falls back
+/// to a coarse ~1ms `timeBeginPeriod` sleep. Always `true` on other platforms, where the
+/// standard library native sleep is backed by high resolution timers.
+///
+/// # Example
+/// ```
+/// if !spin_sleep::high_res_timer_available() {
+///     eprintln!("warning: using coarse timeBeginPeriod native sleep");
+/// }
+/// ```
+pub fn high_res_timer_available() -> bool {
+    #[cfg(all(windows, not(miri)))]
+    return windows::high_res_timer_available();
+
+    #[cfg(not(all(windows, not(miri))))]
+    true
+}
+
+/// [`native_sleep`] overshoot measurements returned by [`measure_native_accuracy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NativeAccuracyReport {
+    /// Least overshoot.
+    pub best: Duration,
+    /// Mean overshoot.
+    pub mean: Duration,
+    /// Most overshoot.
+    pub worst: Duration,
+}
+
+impl NativeAccuracyReport {
+    /// Returns the worst overshoot as nanoseconds suitable for [`SpinSleeper::new`],
+    /// saturating at `u32::MAX`.
+    pub fn worst_ns(&self) -> SubsecondNanoseconds {
+        self.worst.as_nanos().try_into().unwrap_or(u32::MAX)
+    }
+}
+
+/// Measures [`native_sleep`] accuracy by sleeping `probe` duration `samples` times.
+///
+/// Useful at startup to decide the native accuracy to use, or to display in diagnostics.
+/// Returns a default, all zero, report if `samples` is zero.
+///
+/// # Example
+/// ```no_run
+/// use spin_sleep::{measure_native_accuracy, SpinSleeper};
+/// # use std::time::Duration;
+///
+/// let report = measure_native_accuracy(100, Duration::from_millis(1));
+/// println!("native sleep overshoot {report:?}");
+/// let sleeper = SpinSleeper::new(report.worst_ns());
+/// ```
+pub fn measure_native_accuracy(samples: u32, probe: Duration) -> NativeAccuracyReport {
+    if samples == 0 {
+        return NativeAccuracyReport::default();
+    }
+
+    let mut best = Duration::MAX;
+    let mut sum = Duration::ZERO;
+    let mut worst = Duration::ZERO;
+    for _ in 0..samples {
+        let start = Instant::now();
+        native_sleep(probe);
+        let overshoot = start.elapsed().saturating_sub(probe);
+        best = best.min(overshoot);
+        sum += overshoot;
+        worst = worst.max(overshoot);
+    }
+
+    NativeAccuracyReport {
+        best,
+        mean: sum / samples,
+        worst,
+    }
+}
+
+/// Native accuracy measured by [`spawn_calibration`].
+static CALIBRATED_ACCURACY: OnceLock<SubsecondNanoseconds> = OnceLock::new();
+
+/// Spawns a short-lived thread measuring [`native_sleep`] accuracy, see
+/// [`measure_native_accuracy`], caching the worst overshoot for the whole process.
+///
+/// Once complete [`SpinSleeper::calibrated`] uses the cached accuracy. So calibration doesn't
+/// block startup, sleepers can be tightened once it completes. The returned handle may be
+/// joined to wait for the measured accuracy. Measures only once per process.
+///
+/// # Example
+/// ```no_run
+/// use spin_sleep::SpinSleeper;
+///
+/// spin_sleep::spawn_calibration();
+/// # fn running() -> bool { true }
+/// # fn update() {}
+/// while running() {
+///     update();
+///     // default accuracy until calibration completes
+///     SpinSleeper::calibrated().sleep(std::time::Duration::from_millis(5));
+/// }
+/// ```
+pub fn spawn_calibration() -> thread::JoinHandle<SubsecondNanoseconds> {
+    thread::spawn(|| {
+        *CALIBRATED_ACCURACY
+            .get_or_init(|| measure_native_accuracy(50, Duration::from_millis(1)).worst_ns())
+    })
+}
+
+/// Returns the native accuracy measured by [`spawn_calibration`], `None` if not yet complete.
+pub fn calibrated_native_accuracy_ns() -> Option<SubsecondNanoseconds> {
+    CALIBRATED_ACCURACY.get().copied()
+}
+
+impl SpinSleeper {
+    /// Returns a sleeper using the native accuracy measured by [`spawn_calibration`], if
+    /// complete. Otherwise falls back to [`SpinSleeper::default`]. Never blocks.
+    pub fn calibrated() -> Self {
+        match calibrated_native_accuracy_ns() {
+            Some(accuracy) => Self::new(accuracy),
+            None => Self::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn of_resolution() {
+        assert_eq!(
+            AccuracyClass::of(Duration::from_nanos(100)),
+            AccuracyClass::Microsecond
+        );
+        assert_eq!(
+            AccuracyClass::of(Duration::from_millis(1)),
+            AccuracyClass::Millisecond
+        );
+        assert_eq!(
+            AccuracyClass::of(Duration::from_micros(15_600)),
+            AccuracyClass::Coarse
+        );
+    }
+
+    #[test]
+    fn measure_native_accuracy() {
+        let report = super::measure_native_accuracy(5, Duration::from_micros(100));
+        assert!(report.best <= report.mean);
+        assert!(report.mean <= report.worst);
+
+        assert_eq!(
+            super::measure_native_accuracy(0, Duration::from_millis(1)),
+            NativeAccuracyReport::default()
+        );
+    }
+
+    #[test]
+    fn calibration() {
+        let accuracy = spawn_calibration().join().unwrap();
+        assert_eq!(calibrated_native_accuracy_ns(), Some(accuracy));
+        assert_eq!(SpinSleeper::calibrated(), SpinSleeper::new(accuracy));
+    }
+}
@@ -0,0 +1,146 @@
+use super::*;
+
+/// Default [`AdaptiveSpinSleeper::sensitivity`].
+const DEFAULT_SENSITIVITY: f64 = 0.05;
+
+/// Stateful [`SpinSleeper`] that adapts native accuracy to observed native sleep overshoot.
+///
+/// Native sleep accuracy varies with system load. Each sleep measures the native sleep
+/// overshoot, if it exceeds the current accuracy the accuracy is immediately widened to match.
+/// Otherwise the accuracy narrows toward the observed overshoot according to the
+/// [sensitivity](Self::with_sensitivity).
+///
+/// This allows accuracy to be held across load transitions, spinning more when the system
+/// is loaded and less when idle.
+///
+/// # Example
+/// ```no_run
+/// use spin_sleep::{AdaptiveSpinSleeper, SpinSleeper};
+/// # use std::time::Duration;
+///
+/// let mut sleeper = AdaptiveSpinSleeper::new(SpinSleeper::default());
+/// loop {
+///     sleeper.sleep(Duration::from_millis(2));
+///     println!("accuracy: {}ns", sleeper.current_accuracy_ns());
+/// #   break;
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdaptiveSpinSleeper {
+    sleeper: SpinSleeper,
+    sensitivity: f64,
+}
+
+impl AdaptiveSpinSleeper {
+    /// Constructs a new adaptive sleeper starting with the given sleeper's native accuracy.
+    pub fn new(sleeper: SpinSleeper) -> Self {
+        Self {
+            sleeper,
+            sensitivity: DEFAULT_SENSITIVITY,
+        }
+    }
+
+    /// Returns `Self` with the given `sensitivity`, the fraction of the difference
+    /// between the current accuracy and a lower observed overshoot to narrow by after each sleep.
+    ///
+    /// Higher values narrow the accuracy faster after load decreases. Default `0.05`.
+    ///
+    /// # Panics
+    /// Panics if `sensitivity` is not within `0.0..=1.0`.
+    #[track_caller]
+    pub fn with_sensitivity(mut self, sensitivity: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&sensitivity),
+            "`sensitivity` must be within 0.0..=1.0"
+        );
+        self.sensitivity = sensitivity;
+        self
+    }
+
+    /// Returns the configured sensitivity.
+    pub fn sensitivity(&self) -> f64 {
+        self.sensitivity
+    }
+
+    /// Returns the current adapted native accuracy.
+    pub fn current_accuracy_ns(&self) -> SubsecondNanoseconds {
+        self.sleeper.native_accuracy_ns
+    }
+
+    /// Returns a [`SpinSleeper`] using the current adapted native accuracy.
+    pub fn spin_sleeper(&self) -> SpinSleeper {
+        self.sleeper
+    }
+
+    /// Puts the [current thread to sleep](fn.native_sleep.html) for the `duration` less the
+    /// current native accuracy. Then spins until the specified duration has elapsed.
+    ///
+    /// If the deadline, now plus `duration`, is unrepresentable, e.g. `Duration::MAX`,
+    /// natively sleeps the full duration without spinning, i.e. effectively forever.
+    pub fn sleep(&mut self, duration: Duration) {
+        let start = Instant::now();
+        let Some(deadline) = start.checked_add(duration) else {
+            // unrepresentable deadline, natively sleep like `SpinSleeper::sleep`
+            return self.sleeper.native_sleep(duration);
+        };
+        self.adaptive_sleep(start, duration, deadline);
+    }
+
+    /// Puts the [current thread to sleep](fn.native_sleep.html) until the `deadline` less
+    /// the current native accuracy. Then spins until the specified deadline is reached.
+    pub fn sleep_until(&mut self, deadline: Instant) {
+        let start = Instant::now();
+        let duration = deadline.saturating_duration_since(start);
+        self.adaptive_sleep(start, duration, deadline);
+    }
+
+    #[inline]
+    fn adaptive_sleep(&mut self, start: Instant, duration: Duration, deadline: Instant) {
+        let native = self.sleeper.native_portion(duration);
+        if !native.is_zero() {
+            self.sleeper.native_sleep(native);
+            self.observe(start.elapsed().saturating_sub(native));
+        }
+        if native < duration {
+            self.sleeper.spin_until(deadline);
+        }
+    }
+
+    /// Adapts the native accuracy to a new native sleep overshoot sample.
+    fn observe(&mut self, overshoot: Duration) {
+        let overshoot = u32::try_from(overshoot.as_nanos()).unwrap_or(u32::MAX);
+        let accuracy = &mut self.sleeper.native_accuracy_ns;
+        if overshoot > *accuracy {
+            *accuracy = overshoot;
+        } else {
+            *accuracy -= (f64::from(*accuracy - overshoot) * self.sensitivity) as u32;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn widen_immediately() {
+        let mut sleeper = AdaptiveSpinSleeper::new(SpinSleeper::new(100_000));
+        sleeper.observe(Duration::from_micros(300));
+        assert_eq!(sleeper.current_accuracy_ns(), 300_000);
+    }
+
+    #[test]
+    fn narrow_gradually() {
+        let mut sleeper = AdaptiveSpinSleeper::new(SpinSleeper::new(100_000)).with_sensitivity(0.5);
+        sleeper.observe(Duration::from_micros(20));
+        assert_eq!(sleeper.current_accuracy_ns(), 60_000);
+        sleeper.observe(Duration::from_micros(20));
+        assert_eq!(sleeper.current_accuracy_ns(), 40_000);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_sensitivity() {
+        AdaptiveSpinSleeper::new(SpinSleeper::default()).with_sensitivity(1.5);
+    }
+}
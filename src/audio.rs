@@ -0,0 +1,132 @@
+use super::*;
+
+/// [`SpinSleeper`] specialised for repeatedly sleeping a fixed audio block duration.
+///
+/// The native sleep portion is computed once on construction & the final section is spun
+/// using [`SpinStrategy::SpinLoopHint`], the tightest strategy, minimising per-call overhead
+/// & jitter.
+///
+/// # Example
+/// ```no_run
+/// use spin_sleep::AudioSpinSleeper;
+/// # fn process_block() {}
+///
+/// // 128 samples at 44.1kHz, ~2.9ms
+/// let sleeper = AudioSpinSleeper::from_block_samples(128, 44_100);
+/// loop {
+///     process_block();
+///     sleeper.sleep_block();
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AudioSpinSleeper {
+    sleeper: SpinSleeper,
+    block: Duration,
+    /// Precomputed native sleep portion of `block`.
+    native: Duration,
+}
+
+impl AudioSpinSleeper {
+    /// Constructs a new sleeper for the given `block` duration using the default
+    /// native accuracy.
+    pub fn new(block: Duration) -> Self {
+        Self::with_native_accuracy_ns(block, default_native_accuracy_ns())
+    }
+
+    /// Constructs a new sleeper for a block of `samples` at `sample_rate` samples per second,
+    /// using the default native accuracy.
+    ///
+    /// # Panics
+    /// Panics if `sample_rate` is zero.
+    #[track_caller]
+    pub fn from_block_samples(samples: u32, sample_rate: u32) -> Self {
+        assert!(sample_rate > 0, "`sample_rate` must be non-zero.");
+        let nanos = u64::from(samples) * 1_000_000_000 / u64::from(sample_rate);
+        Self::new(Duration::from_nanos(nanos))
+    }
+
+    /// Constructs a new sleeper for the given `block` duration & native accuracy.
+    pub fn with_native_accuracy_ns(
+        block: Duration,
+        native_accuracy_ns: SubsecondNanoseconds,
+    ) -> Self {
+        let sleeper =
+            SpinSleeper::new(native_accuracy_ns).with_spin_strategy(SpinStrategy::SpinLoopHint);
+        Self {
+            sleeper,
+            block,
+            native: sleeper.native_portion(block),
+        }
+    }
+
+    /// Returns the block duration.
+    pub fn block(&self) -> Duration {
+        self.block
+    }
+
+    /// Returns the inner [`SpinSleeper`].
+    pub fn spin_sleeper(&self) -> SpinSleeper {
+        self.sleeper
+    }
+
+    /// Sleeps for the block duration.
+    #[inline]
+    pub fn sleep_block(&self) {
+        let deadline = Instant::now() + self.block;
+        self.sleep_block_to(deadline, self.native);
+    }
+
+    /// Sleeps for the block duration after `start`, e.g. the start of the last block
+    /// processing, so processing time is included in the block.
+    ///
+    /// If processing took longer than the block duration this returns immediately.
+    #[inline]
+    pub fn sleep_block_from(&self, start: Instant) {
+        let deadline = start + self.block;
+        let now = Instant::now();
+        if now >= deadline {
+            return;
+        }
+        self.sleep_block_to(deadline, self.native.saturating_sub(now - start));
+    }
+
+    #[inline]
+    fn sleep_block_to(&self, deadline: Instant, native: Duration) {
+        if cfg!(miri) {
+            return thread::sleep(deadline.saturating_duration_since(Instant::now()));
+        }
+        if !native.is_zero() {
+            self.sleeper.native_sleep(native);
+        }
+        busy_wait_until(deadline, SpinStrategy::SpinLoopHint);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_block_samples() {
+        let sleeper = AudioSpinSleeper::from_block_samples(441, 44_100);
+        assert_eq!(sleeper.block(), Duration::from_millis(10));
+    }
+
+    #[test]
+    fn sleep_block() {
+        let sleeper = AudioSpinSleeper::new(Duration::from_millis(2));
+        let start = Instant::now();
+        sleeper.sleep_block();
+        assert!(start.elapsed() >= Duration::from_millis(2));
+    }
+
+    #[test]
+    fn sleep_block_from() {
+        let sleeper = AudioSpinSleeper::new(Duration::from_millis(2));
+        let start = Instant::now();
+        thread::sleep(Duration::from_millis(1));
+        sleeper.sleep_block_from(start);
+        assert!(start.elapsed() >= Duration::from_millis(2));
+        assert!(start.elapsed() < Duration::from_millis(50));
+    }
+}
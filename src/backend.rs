@@ -0,0 +1,297 @@
+use super::*;
+
+/// Native sleep & [`Clock`] primitives used by [`SpinSleeper::sleep_with_backend`].
+///
+/// Allows using the native sleep + spin logic of [`SpinSleeper`] without the standard
+/// library, e.g. plugging in an RTOS/HAL delay & timer.
+///
+/// # Example
+/// ```
+/// use spin_sleep::{Clock, Nanoseconds, SleepBackend, SpinSleeper};
+/// # use std::time::{Duration, Instant};
+///
+/// struct HalBackend;
+///
+/// impl SleepBackend for HalBackend {
+///     fn native_sleep(&self, duration: Duration) {
+///         // e.g. hal::delay(duration)
+/// #       std::thread::sleep(duration);
+///     }
+/// }
+///
+/// impl Clock for HalBackend {
+///     fn now(&self) -> Nanoseconds {
+///         // e.g. hal::timer_ns()
+/// #       static EPOCH: std::sync::OnceLock<Instant> = std::sync::OnceLock::new();
+/// #       EPOCH.get_or_init(Instant::now).elapsed().as_nanos() as _
+///     }
+/// }
+///
+/// SpinSleeper::new(100_000).sleep_with_backend(&HalBackend, Duration::from_millis(1));
+/// ```
+pub trait SleepBackend: Clock {
+    /// Sleeps for at least the given duration. **Should not spin.**
+    fn native_sleep(&self, duration: Duration);
+}
+
+impl SpinSleeper {
+    /// Natively sleeps using the `backend` for the `duration` less the configured native
+    /// accuracy. Then spins, reading the `backend` clock, until the duration has elapsed.
+    ///
+    /// Clock de-biasing is not supported and ignored. Without the **std** feature
+    /// [`SpinStrategy::YieldThread`] is equivalent to [`SpinStrategy::SpinLoopHint`].
+    pub fn sleep_with_backend<B: SleepBackend + ?Sized>(self, backend: &B, duration: Duration) {
+        let native = self.native_portion(duration);
+        if self.native_accuracy_ns == 0 || native == duration {
+            return backend.native_sleep(duration);
+        }
+        let duration_ns = Nanoseconds::try_from(duration.as_nanos()).unwrap_or(Nanoseconds::MAX);
+        let deadline = backend.now().saturating_add(duration_ns);
+        self.backend_sleep(backend, native, deadline);
+    }
+
+    /// Natively sleeps using the `backend` until the `deadline`, in `backend` clock
+    /// nanoseconds, less the configured native accuracy. Then spins, reading the `backend`
+    /// clock, until the deadline is reached.
+    ///
+    /// See [`SpinSleeper::sleep_with_backend`].
+    pub fn sleep_until_with_backend<B: SleepBackend + ?Sized>(
+        self,
+        backend: &B,
+        deadline: Nanoseconds,
+    ) {
+        let duration = Duration::from_nanos(deadline.saturating_sub(backend.now()));
+        let native = self.native_portion(duration);
+        if self.native_accuracy_ns == 0 || native == duration {
+            return backend.native_sleep(duration);
+        }
+        self.backend_sleep(backend, native, deadline);
+    }
+
+    /// Spins, reading the `clock`, until the `duration` has elapsed. Never natively sleeps.
+    ///
+    /// Useful on bare-metal targets without any native sleep/delay, only a monotonic timer.
+    /// [`SpinSleeper::with_max_spins`] is ignored as there is no native sleep to yield with.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep::{Clock, Nanoseconds, SpinSleeper, SpinStrategy};
+    /// # use std::time::{Duration, Instant};
+    ///
+    /// struct Timer;
+    ///
+    /// impl Clock for Timer {
+    ///     fn now(&self) -> Nanoseconds {
+    ///         // e.g. read a hardware timer counter
+    /// #       static EPOCH: std::sync::OnceLock<Instant> = std::sync::OnceLock::new();
+    /// #       EPOCH.get_or_init(Instant::now).elapsed().as_nanos() as _
+    ///     }
+    /// }
+    ///
+    /// SpinSleeper::default()
+    ///     .with_spin_strategy(SpinStrategy::SpinLoopHint)
+    ///     .spin_with_clock(&Timer, Duration::from_micros(50));
+    /// ```
+    pub fn spin_with_clock<C: Clock + ?Sized>(self, clock: &C, duration: Duration) {
+        let duration_ns = Nanoseconds::try_from(duration.as_nanos()).unwrap_or(Nanoseconds::MAX);
+        self.spin_until_with_clock(clock, clock.now().saturating_add(duration_ns));
+    }
+
+    /// Spins, reading the `clock`, until the `deadline`, in `clock` nanoseconds, is reached.
+    /// Never natively sleeps.
+    ///
+    /// See [`SpinSleeper::spin_with_clock`].
+    pub fn spin_until_with_clock<C: Clock + ?Sized>(self, clock: &C, deadline: Nanoseconds) {
+        loop {
+            let now = clock.now();
+            if now >= deadline {
+                break;
+            }
+            self.spin_strategy
+                .spin(Duration::from_nanos(deadline - now));
+        }
+    }
+
+    /// Natively sleeps `native` then spins until the `deadline`.
+    fn backend_sleep<B: SleepBackend + ?Sized>(
+        self,
+        backend: &B,
+        native: Duration,
+        deadline: Nanoseconds,
+    ) {
+        if !native.is_zero() {
+            backend.native_sleep(native);
+        }
+
+        let mut spins = 0;
+        loop {
+            let now = backend.now();
+            if now >= deadline {
+                break;
+            }
+            match self.max_spins {
+                Some(max_spins) if spins >= max_spins => {
+                    backend.native_sleep(MAX_SPINS_NATIVE_SLEEP);
+                    spins = 0;
+                }
+                _ => {
+                    self.spin_strategy
+                        .spin(Duration::from_nanos(deadline - now));
+                    spins += 1;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use std::cell::{Cell, RefCell};
+
+    /// Fake clock advancing 1µs per read & by the slept duration per native sleep.
+    #[derive(Default)]
+    struct MockBackend {
+        now: Cell<Nanoseconds>,
+        sleeps: RefCell<Vec<Duration>>,
+    }
+
+    impl SleepBackend for MockBackend {
+        fn native_sleep(&self, duration: Duration) {
+            self.now
+                .set(self.now.get() + duration.as_nanos() as Nanoseconds);
+            self.sleeps.borrow_mut().push(duration);
+        }
+    }
+
+    impl Clock for MockBackend {
+        fn now(&self) -> Nanoseconds {
+            self.now.set(self.now.get() + 1_000);
+            self.now.get()
+        }
+    }
+
+    #[test]
+    fn native_then_spin() {
+        let backend = MockBackend::default();
+        SpinSleeper::new(100_000).sleep_with_backend(&backend, Duration::from_millis(1));
+
+        assert_eq!(*backend.sleeps.borrow(), [Duration::from_micros(900)]);
+        // started at 1µs, so should spin until >= 1001µs
+        assert_eq!(backend.now.get(), 1_001_000);
+    }
+
+    #[test]
+    fn above_spin_threshold() {
+        let backend = MockBackend::default();
+        SpinSleeper::new(100_000)
+            .with_spin_threshold(Duration::from_micros(500))
+            .sleep_with_backend(&backend, Duration::from_millis(1));
+
+        assert_eq!(*backend.sleeps.borrow(), [Duration::from_millis(1)]);
+        // no clock reads
+        assert_eq!(backend.now.get(), 1_000_000);
+    }
+
+    /// Fake clock advancing a random amount per read & by the slept duration plus a random
+    /// overshoot per native sleep.
+    struct RandomBackend {
+        now: Cell<Nanoseconds>,
+        rng: RefCell<StdRng>,
+    }
+
+    impl SleepBackend for RandomBackend {
+        fn native_sleep(&self, duration: Duration) {
+            let overshoot = self.rng.borrow_mut().gen_range(0..2_000_000);
+            self.now
+                .set(self.now.get() + duration.as_nanos() as Nanoseconds + overshoot);
+        }
+    }
+
+    impl Clock for RandomBackend {
+        fn now(&self) -> Nanoseconds {
+            let step = self.rng.borrow_mut().gen_range(0..50_000);
+            self.now.set(self.now.get() + step);
+            self.now.get()
+        }
+    }
+
+    fn random_sleeper(rng: &mut StdRng) -> SpinSleeper {
+        let mut sleeper = SpinSleeper::new(rng.gen_range(0..2_000_000))
+            .with_spin_strategy(SpinStrategy::SpinLoopHint);
+        if rng.gen() {
+            sleeper = sleeper.with_max_spins(rng.gen_range(0..100));
+        }
+        if rng.gen() {
+            sleeper =
+                sleeper.with_spin_threshold(Duration::from_nanos(rng.gen_range(0..5_000_000)));
+        }
+        if rng.gen() {
+            sleeper = sleeper.with_spin_fraction(rng.gen_range(0.0..=1.0));
+        }
+        sleeper
+    }
+
+    /// Property: `sleep_with_backend(d)` never returns before `d` has elapsed.
+    #[test]
+    fn prop_sleep_never_early() {
+        let mut rng = StdRng::seed_from_u64(458);
+        for _ in 0..10_000 {
+            let sleeper = random_sleeper(&mut rng);
+            let start = rng.gen::<Nanoseconds>() >> 2;
+            let backend = RandomBackend {
+                now: Cell::new(start),
+                rng: RefCell::new(StdRng::seed_from_u64(rng.gen())),
+            };
+            let duration = Duration::from_nanos(rng.gen_range(0..5_000_000));
+
+            sleeper.sleep_with_backend(&backend, duration);
+            assert!(
+                backend.now.get() - start >= duration.as_nanos() as Nanoseconds,
+                "{sleeper:?} {duration:?}"
+            );
+        }
+    }
+
+    /// Property: `sleep_until_with_backend(deadline)` never returns before `deadline`.
+    #[test]
+    fn prop_sleep_until_never_early() {
+        let mut rng = StdRng::seed_from_u64(4580);
+        for _ in 0..10_000 {
+            let sleeper = random_sleeper(&mut rng);
+            let start = rng.gen::<Nanoseconds>() >> 2;
+            let backend = RandomBackend {
+                now: Cell::new(start),
+                rng: RefCell::new(StdRng::seed_from_u64(rng.gen())),
+            };
+            let deadline = start + rng.gen_range(0..5_000_000);
+
+            sleeper.sleep_until_with_backend(&backend, deadline);
+            assert!(backend.now.get() >= deadline, "{sleeper:?}");
+        }
+    }
+
+    #[test]
+    fn spin_with_clock() {
+        let backend = MockBackend::default();
+        SpinSleeper::new(100_000)
+            .with_max_spins(10)
+            .spin_with_clock(&backend, Duration::from_micros(100));
+
+        assert!(backend.sleeps.borrow().is_empty());
+        // started at 1µs, so should spin until >= 101µs
+        assert_eq!(backend.now.get(), 101_000);
+    }
+
+    #[test]
+    fn max_spins() {
+        let backend = MockBackend::default();
+        SpinSleeper::new(100_000)
+            .with_max_spins(10)
+            .sleep_with_backend(&backend, Duration::from_micros(100));
+
+        assert!(backend.sleeps.borrow().len() > 1);
+        assert!(backend.now.get() >= 101_000);
+    }
+}
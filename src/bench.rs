@@ -0,0 +1,98 @@
+use super::*;
+
+/// Sleep accuracy measurements for a single duration, returned by [`bench_sleeper`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BenchResult {
+    /// Requested sleep duration.
+    pub duration: Duration,
+    /// Mean time slept beyond `duration`.
+    pub mean_overshoot: Duration,
+    /// Least time slept beyond `duration`.
+    pub best_overshoot: Duration,
+    /// Most time slept beyond `duration`.
+    pub worst_overshoot: Duration,
+    /// Mean number of spin iterations per sleep.
+    pub mean_spins: u64,
+}
+
+/// Sleeps `iters` times for each of the `durations` using `sleeper` returning overshoot &
+/// spin count measurements for each duration.
+///
+/// Sleeps use [`SpinSleeper::sleep_instrumented`], so measure the same path as
+/// [`SpinSleeper::sleep`] with all the `sleeper` configuration.
+///
+/// Useful to pick the best [`SpinSleeper`] configuration for the current machine at runtime
+/// or to regression-test timing in CI on dedicated hardware.
+///
+/// # Panics
+/// Panics if `iters` is zero.
+///
+/// # Example
+/// ```no_run
+/// use spin_sleep::{bench_sleeper, SpinSleeper};
+/// # use std::time::Duration;
+///
+/// let durations = [Duration::from_micros(100), Duration::from_millis(1)];
+/// for accuracy in [100_000, 500_000, 1_000_000] {
+///     let results = bench_sleeper(SpinSleeper::new(accuracy), &durations, 100);
+///     println!("{accuracy}ns: {results:#?}");
+/// }
+/// ```
+#[track_caller]
+pub fn bench_sleeper(sleeper: SpinSleeper, durations: &[Duration], iters: u32) -> Vec<BenchResult> {
+    assert!(iters > 0, "`iters` must be non-zero.");
+
+    durations
+        .iter()
+        .map(|&duration| {
+            let mut sum = Duration::ZERO;
+            let mut best = Duration::MAX;
+            let mut worst = Duration::ZERO;
+            let mut spins = 0;
+
+            for _ in 0..iters {
+                let stats = sleeper.sleep_instrumented(duration);
+                sum += stats.overshoot();
+                best = best.min(stats.overshoot());
+                worst = worst.max(stats.overshoot());
+                spins += stats.spin_iterations();
+            }
+
+            BenchResult {
+                duration,
+                mean_overshoot: sum / iters,
+                best_overshoot: best,
+                worst_overshoot: worst,
+                mean_spins: spins / u64::from(iters),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bench_sleeper() {
+        let durations = [Duration::from_micros(50), Duration::from_millis(1)];
+        let results = super::bench_sleeper(SpinSleeper::new(100_000), &durations, 3);
+
+        assert_eq!(results.len(), 2);
+        for (result, duration) in results.iter().zip(durations) {
+            assert_eq!(result.duration, duration);
+            assert!(result.best_overshoot <= result.mean_overshoot);
+            assert!(result.mean_overshoot <= result.worst_overshoot);
+        }
+        // 50µs is entirely spun
+        assert!(results[0].mean_spins > 0);
+    }
+
+    #[test]
+    fn bench_sleeper_config() {
+        let durations = [Duration::from_micros(50)];
+        let sleeper = SpinSleeper::new(100_000).with_max_spins(0);
+        let results = super::bench_sleeper(sleeper, &durations, 3);
+        assert_eq!(results[0].mean_spins, 0);
+    }
+}
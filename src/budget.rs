@@ -0,0 +1,61 @@
+use super::*;
+
+impl SpinSleeper {
+    /// Sleeps like [`SpinSleeper::sleep`], but abandons spinning once it has spun for
+    /// `max_spin`, bounding worst case CPU usage if the native sleep badly overshoots.
+    ///
+    /// Returns `true` if the full `duration` elapsed, `false` if the spin was abandoned early.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::time::Duration;
+    /// let sleeper = spin_sleep::SpinSleeper::default();
+    /// let met = sleeper.sleep_at_most(Duration::from_millis(5), Duration::from_millis(2));
+    /// if !met {
+    ///     eprintln!("spin budget exceeded");
+    /// }
+    /// ```
+    pub fn sleep_at_most(self, duration: Duration, max_spin: Duration) -> bool {
+        let start = Instant::now();
+        let Some(deadline) = start.checked_add(duration) else {
+            self.native_sleep(duration);
+            return true;
+        };
+        let native = self.native_portion(duration);
+        if self.native_accuracy_ns == 0 || native == duration {
+            self.native_sleep(duration);
+            return true;
+        }
+        if !native.is_zero() {
+            self.native_sleep(native);
+        }
+
+        let spin_end = Instant::now()
+            .checked_add(max_spin)
+            .map_or(deadline, |end| end.min(deadline));
+        self.spin_until(spin_end);
+        spin_end == deadline || Instant::now() >= deadline
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn met() {
+        let duration = Duration::from_millis(2);
+        let start = Instant::now();
+        assert!(SpinSleeper::default().sleep_at_most(duration, Duration::from_secs(1)));
+        assert!(start.elapsed() >= duration);
+    }
+
+    #[test]
+    fn abandoned() {
+        // spin everything, with a tiny spin budget
+        let sleeper = SpinSleeper::new(u32::MAX);
+        let start = Instant::now();
+        assert!(!sleeper.sleep_at_most(Duration::from_millis(100), Duration::from_micros(100)));
+        assert!(start.elapsed() < Duration::from_millis(50));
+    }
+}
@@ -0,0 +1,102 @@
+use super::*;
+
+/// Builds a [`SpinSleeper`], see [`SpinSleeper::builder`].
+///
+/// Unset options use the [`SpinSleeper::default`] values.
+///
+/// # Example
+/// ```
+/// use spin_sleep::{SpinSleeper, SpinStrategy};
+/// # use std::time::Duration;
+///
+/// let sleeper = SpinSleeper::builder()
+///     .native_accuracy(Duration::from_micros(500))
+///     .spin_strategy(SpinStrategy::SpinLoopHint)
+///     .build();
+/// assert_eq!(sleeper.native_accuracy_ns(), 500_000);
+/// assert_eq!(sleeper.spin_strategy(), SpinStrategy::SpinLoopHint);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpinSleeperBuilder {
+    native_accuracy: Option<Duration>,
+    spin_strategy: Option<SpinStrategy>,
+    #[cfg(feature = "metrics")]
+    metrics_prefix: Option<&'static str>,
+}
+
+impl SpinSleeperBuilder {
+    /// Sets the native sleep accuracy in nanoseconds.
+    /// See [`SpinSleeper::new`] for details.
+    ///
+    /// Defaults to [`default_native_accuracy_ns`].
+    pub fn native_accuracy_ns(self, accuracy: SubsecondNanoseconds) -> Self {
+        self.native_accuracy(Duration::from_nanos(accuracy.into()))
+    }
+
+    /// Sets the native sleep accuracy, saturating at `u32::MAX` nanoseconds.
+    /// See [`SpinSleeper::with_native_accuracy`].
+    ///
+    /// Defaults to [`default_native_accuracy_ns`].
+    pub fn native_accuracy(mut self, accuracy: Duration) -> Self {
+        self.native_accuracy = Some(accuracy);
+        self
+    }
+
+    /// Sets the [`SpinStrategy`]. Defaults to the OS default, see [`SpinStrategy`].
+    pub fn spin_strategy(mut self, strategy: SpinStrategy) -> Self {
+        self.spin_strategy = Some(strategy);
+        self
+    }
+
+    /// Sets the metric name prefix, see [`SpinSleeper::with_metrics_prefix`].
+    #[cfg(feature = "metrics")]
+    pub fn metrics_prefix(mut self, prefix: &'static str) -> Self {
+        self.metrics_prefix = Some(prefix);
+        self
+    }
+
+    /// Builds the [`SpinSleeper`].
+    pub fn build(self) -> SpinSleeper {
+        let mut sleeper = SpinSleeper::default();
+        if let Some(accuracy) = self.native_accuracy {
+            sleeper = sleeper.with_native_accuracy(accuracy);
+        }
+        if let Some(strategy) = self.spin_strategy {
+            sleeper = sleeper.with_spin_strategy(strategy);
+        }
+        #[cfg(feature = "metrics")]
+        if let Some(prefix) = self.metrics_prefix {
+            sleeper = sleeper.with_metrics_prefix(prefix);
+        }
+        sleeper
+    }
+}
+
+impl SpinSleeper {
+    /// Returns a [`SpinSleeperBuilder`] with which to build a `SpinSleeper`.
+    pub fn builder() -> SpinSleeperBuilder {
+        SpinSleeperBuilder::default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn defaults() {
+        assert_eq!(SpinSleeper::builder().build(), SpinSleeper::default());
+    }
+
+    #[test]
+    fn native_accuracy_ns() {
+        let sleeper = SpinSleeper::builder()
+            .native_accuracy_ns(123_456)
+            .spin_strategy(SpinStrategy::YieldThread)
+            .build();
+        assert_eq!(
+            sleeper,
+            SpinSleeper::new(123_456).with_spin_strategy(SpinStrategy::YieldThread)
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use super::*;
+use std::sync::mpsc::{Receiver, RecvTimeoutError, TryRecvError};
+
+/// Receives a value from the channel, waiting at most `timeout`, with precise timeout accuracy.
+///
+/// Unlike [`Receiver::recv_timeout`] this repeatedly polls [`Receiver::try_recv`] with short
+/// precise spin-sleeps in between until the deadline. This achieves tight timeout accuracy
+/// but is a busy-wait, consuming a CPU core for the entire wait.
+///
+/// See [`recv_timeout_native`] for a less CPU intensive alternative.
+///
+/// # Example
+/// ```
+/// use std::{sync::mpsc, time::Duration};
+///
+/// let (tx, rx) = mpsc::channel::<u32>();
+/// assert_eq!(
+///     spin_sleep::recv_timeout(&rx, Duration::from_micros(100)),
+///     Err(mpsc::RecvTimeoutError::Timeout)
+/// );
+///
+/// tx.send(123).unwrap();
+/// assert_eq!(
+///     spin_sleep::recv_timeout(&rx, Duration::from_micros(100)),
+///     Ok(123)
+/// );
+/// ```
+pub fn recv_timeout<T>(rx: &Receiver<T>, timeout: Duration) -> Result<T, RecvTimeoutError> {
+    match Instant::now().checked_add(timeout) {
+        Some(deadline) => poll_recv_until(rx, deadline),
+        None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    }
+}
+
+/// Receives a value from the channel, waiting at most `timeout`, with precise timeout accuracy.
+///
+/// Native-biased variant of [`recv_timeout`]. Uses [`Receiver::recv_timeout`] to wait the
+/// `timeout` less the default native accuracy, then polls precisely like [`recv_timeout`]
+/// for the remainder. This only busy-waits for the final native accuracy section.
+pub fn recv_timeout_native<T>(rx: &Receiver<T>, timeout: Duration) -> Result<T, RecvTimeoutError> {
+    let Some(deadline) = Instant::now().checked_add(timeout) else {
+        return rx.recv().map_err(|_| RecvTimeoutError::Disconnected);
+    };
+
+    match rx.recv_timeout(SpinSleeper::default().native_portion(timeout)) {
+        Err(RecvTimeoutError::Timeout) => poll_recv_until(rx, deadline),
+        result => result,
+    }
+}
+
+fn poll_recv_until<T>(rx: &Receiver<T>, deadline: Instant) -> Result<T, RecvTimeoutError> {
+    with_deadline(deadline, || match rx.try_recv() {
+        Ok(value) => Some(Ok(value)),
+        Err(TryRecvError::Disconnected) => Some(Err(RecvTimeoutError::Disconnected)),
+        Err(TryRecvError::Empty) => None,
+    })
+    .unwrap_or(Err(RecvTimeoutError::Timeout))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::mpsc;
+
+    #[test]
+    fn timeout() {
+        let (_tx, rx) = mpsc::channel::<()>();
+        let timeout = Duration::from_millis(2);
+
+        let before = Instant::now();
+        assert_eq!(recv_timeout(&rx, timeout), Err(RecvTimeoutError::Timeout));
+        assert!(before.elapsed() >= timeout);
+
+        let before = Instant::now();
+        assert_eq!(
+            recv_timeout_native(&rx, timeout),
+            Err(RecvTimeoutError::Timeout)
+        );
+        assert!(before.elapsed() >= timeout);
+    }
+
+    #[test]
+    fn receive() {
+        let (tx, rx) = mpsc::channel();
+        tx.send(1).unwrap();
+        tx.send(2).unwrap();
+        assert_eq!(recv_timeout(&rx, Duration::from_millis(2)), Ok(1));
+        assert_eq!(recv_timeout_native(&rx, Duration::from_millis(2)), Ok(2));
+    }
+
+    #[test]
+    fn disconnected() {
+        let (tx, rx) = mpsc::channel::<()>();
+        drop(tx);
+        assert_eq!(
+            recv_timeout(&rx, Duration::from_millis(2)),
+            Err(RecvTimeoutError::Disconnected)
+        );
+        assert_eq!(
+            recv_timeout_native(&rx, Duration::from_millis(2)),
+            Err(RecvTimeoutError::Disconnected)
+        );
+    }
+}
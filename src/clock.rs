@@ -0,0 +1,110 @@
+use super::*;
+
+/// Monotonic clock source, allowing time-gated logic to be tested deterministically with
+/// a mock clock.
+///
+/// Used by [`SleepBackend`] & `LoopHelper`/`spin_sleep_util::RateReporter` rate reporting.
+pub trait Clock {
+    /// Returns the current monotonic time in nanoseconds from an arbitrary fixed epoch.
+    fn now(&self) -> Nanoseconds;
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    #[inline]
+    fn now(&self) -> Nanoseconds {
+        (**self).now()
+    }
+}
+
+/// [`Clock`] using [`Instant`], returning nanoseconds since the [`Deadline`] epoch.
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StdClock;
+
+#[cfg(feature = "std")]
+impl Clock for StdClock {
+    #[inline]
+    fn now(&self) -> Nanoseconds {
+        Deadline::now().as_nanos()
+    }
+}
+
+#[cfg(feature = "std")]
+impl SpinSleeper {
+    /// Natively sleeps, like [`SpinSleeper::sleep`], for the `duration` less the configured
+    /// native accuracy. Then spins, reading the `clock`, until the duration has elapsed.
+    ///
+    /// Allows spinning against a lower overhead time source than [`Instant`], e.g.
+    /// `clock_gettime(CLOCK_MONOTONIC_RAW)` or a TSC reader.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep::{Clock, Nanoseconds, SpinSleeper};
+    /// # use std::time::Duration;
+    ///
+    /// struct MonotonicRaw;
+    ///
+    /// impl Clock for MonotonicRaw {
+    ///     fn now(&self) -> Nanoseconds {
+    ///         // e.g. clock_gettime(CLOCK_MONOTONIC_RAW)
+    /// #       spin_sleep::StdClock.now()
+    ///     }
+    /// }
+    ///
+    /// SpinSleeper::default().sleep_with_clock(&MonotonicRaw, Duration::from_millis(1));
+    /// ```
+    pub fn sleep_with_clock<C: Clock + ?Sized>(self, clock: &C, duration: Duration) {
+        self.sleep_with_backend(&NativeWithClock(self, clock), duration);
+    }
+
+    /// Natively sleeps, like [`SpinSleeper::sleep_until`], until the `deadline`, in `clock`
+    /// nanoseconds, less the configured native accuracy. Then spins, reading the `clock`,
+    /// until the deadline is reached.
+    ///
+    /// See [`SpinSleeper::sleep_with_clock`].
+    pub fn sleep_until_with_clock<C: Clock + ?Sized>(self, clock: &C, deadline: Nanoseconds) {
+        self.sleep_until_with_backend(&NativeWithClock(self, clock), deadline);
+    }
+}
+
+/// [`SleepBackend`] using the [`SpinSleeper`] native sleep & a custom [`Clock`].
+#[cfg(feature = "std")]
+struct NativeWithClock<'c, C: ?Sized>(SpinSleeper, &'c C);
+
+#[cfg(feature = "std")]
+impl<C: Clock + ?Sized> Clock for NativeWithClock<'_, C> {
+    #[inline]
+    fn now(&self) -> Nanoseconds {
+        self.1.now()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: Clock + ?Sized> SleepBackend for NativeWithClock<'_, C> {
+    #[inline]
+    fn native_sleep(&self, duration: Duration) {
+        // wasm32-unknown-unknown threads cannot sleep
+        #[cfg(all(target_family = "wasm", target_os = "unknown"))]
+        self.0.spin_with_clock(self.1, duration);
+
+        #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+        self.0.native_sleep(duration);
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sleep_with_clock() {
+        let duration = Duration::from_millis(2);
+        let start = Instant::now();
+        SpinSleeper::default().sleep_with_clock(&StdClock, duration);
+        assert!(start.elapsed() >= duration);
+
+        let deadline = StdClock.now() + 1_000_000;
+        SpinSleeper::default().sleep_until_with_clock(&StdClock, deadline);
+        assert!(StdClock.now() >= deadline);
+    }
+}
@@ -0,0 +1,136 @@
+use super::*;
+
+/// Weight of each new native sleep error sample in the running estimate, `1 / ERROR_WEIGHT`.
+const ERROR_WEIGHT: i64 = 8;
+
+/// Stateful [`SpinSleeper`] that compensates for native sleep *undershoot*.
+///
+/// [`SpinSleeper`] assumes native sleep tends to over-sleep, so natively sleeps less than the
+/// requested duration and spins the rest. On systems where native sleep reliably *under*-sleeps
+/// this means unnecessarily spinning a long predictable tail.
+///
+/// This sleeper keeps a running estimate of native sleep error and, if native sleep
+/// consistently returns early, increases the native sleep target (never beyond the requested
+/// duration) to reduce spinning. Overshooting samples reduce the compensation.
+///
+/// # Example
+/// ```no_run
+/// use spin_sleep::{CompensatedSpinSleeper, SpinSleeper};
+/// # use std::time::Duration;
+///
+/// let mut sleeper = CompensatedSpinSleeper::new(SpinSleeper::default());
+/// loop {
+///     sleeper.sleep(Duration::from_millis(2));
+/// #   break;
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompensatedSpinSleeper {
+    sleeper: SpinSleeper,
+    /// Running estimate of native sleep error, actual minus requested, in nanoseconds.
+    error_estimate_ns: i64,
+}
+
+impl CompensatedSpinSleeper {
+    /// Constructs a new compensated sleeper with no initial compensation.
+    pub fn new(sleeper: SpinSleeper) -> Self {
+        Self {
+            sleeper,
+            error_estimate_ns: 0,
+        }
+    }
+
+    /// Returns the wrapped [`SpinSleeper`].
+    pub fn spin_sleeper(&self) -> SpinSleeper {
+        self.sleeper
+    }
+
+    /// Returns the current undershoot compensation, i.e. how much longer than normal
+    /// native sleeps will be requested.
+    pub fn compensation(&self) -> Duration {
+        Duration::from_nanos(self.error_estimate_ns.min(0).unsigned_abs())
+    }
+
+    /// Puts the [current thread to sleep](fn.native_sleep.html) for the `duration` less the
+    /// configured native accuracy, plus the current compensation. Then spins until the
+    /// specified duration has elapsed.
+    ///
+    /// If the deadline, now plus `duration`, is unrepresentable, e.g. `Duration::MAX`,
+    /// natively sleeps the full duration without spinning, i.e. effectively forever.
+    pub fn sleep(&mut self, duration: Duration) {
+        let start = Instant::now();
+        let Some(deadline) = start.checked_add(duration) else {
+            // unrepresentable deadline, natively sleep like `SpinSleeper::sleep`
+            return self.sleeper.native_sleep(duration);
+        };
+        self.compensated_sleep(start, duration, deadline);
+    }
+
+    /// Puts the [current thread to sleep](fn.native_sleep.html) until the `deadline` less
+    /// the configured native accuracy, plus the current compensation. Then spins until the
+    /// specified deadline is reached.
+    pub fn sleep_until(&mut self, deadline: Instant) {
+        let start = Instant::now();
+        let duration = deadline.saturating_duration_since(start);
+        self.compensated_sleep(start, duration, deadline);
+    }
+
+    #[inline]
+    fn compensated_sleep(&mut self, start: Instant, duration: Duration, deadline: Instant) {
+        let native = self.sleeper.native_portion(duration);
+        if !native.is_zero() {
+            let native = (native + self.compensation()).min(duration);
+            self.sleeper.native_sleep(native);
+            self.record(native, start.elapsed());
+        }
+        if native < duration {
+            self.sleeper.spin_until(deadline);
+        }
+    }
+
+    /// Updates the running native sleep error estimate with a new sample.
+    fn record(&mut self, requested: Duration, actual: Duration) {
+        let error = i64::try_from(actual.as_nanos() as i128 - requested.as_nanos() as i128)
+            .unwrap_or(i64::MAX);
+        self.error_estimate_ns += (error - self.error_estimate_ns) / ERROR_WEIGHT;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn consistent_undershoot_compensates() {
+        let mut sleeper = CompensatedSpinSleeper::new(SpinSleeper::new(100_000));
+        for _ in 0..100 {
+            sleeper.record(Duration::from_micros(900), Duration::from_micros(850));
+        }
+        let compensation = sleeper.compensation();
+        assert!(compensation > Duration::from_micros(45), "{compensation:?}");
+        assert!(
+            compensation <= Duration::from_micros(50),
+            "{compensation:?}"
+        );
+    }
+
+    #[test]
+    fn overshoot_does_not_compensate() {
+        let mut sleeper = CompensatedSpinSleeper::new(SpinSleeper::new(100_000));
+        for _ in 0..100 {
+            sleeper.record(Duration::from_micros(900), Duration::from_micros(950));
+        }
+        assert_eq!(sleeper.compensation(), Duration::ZERO);
+    }
+
+    #[test]
+    fn overshoot_reduces_compensation() {
+        let mut sleeper = CompensatedSpinSleeper::new(SpinSleeper::new(100_000));
+        for _ in 0..100 {
+            sleeper.record(Duration::from_micros(900), Duration::from_micros(850));
+        }
+        let before = sleeper.compensation();
+        sleeper.record(Duration::from_micros(900), Duration::from_micros(1000));
+        assert!(sleeper.compensation() < before);
+    }
+}
@@ -0,0 +1,116 @@
+use super::*;
+
+/// Monotonic point in time stored as a plain `u64` of nanoseconds since a crate-managed epoch.
+///
+/// Unlike [`Instant`] this can be stored in atomics or compactly in collections, e.g. timer
+/// wheels & heaps. All `Deadline`s share the same epoch, captured once on first use.
+///
+/// # Example
+/// ```
+/// use spin_sleep::{Deadline, SpinSleeper};
+/// use std::sync::atomic::{AtomicU64, Ordering};
+/// # use std::time::Duration;
+///
+/// let next = AtomicU64::new(Deadline::after(Duration::from_millis(1)).as_nanos());
+///
+/// let deadline = Deadline::from_nanos(next.load(Ordering::Relaxed));
+/// SpinSleeper::default().sleep_to(deadline);
+/// assert!(deadline.is_past());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Deadline(Nanoseconds);
+
+impl Deadline {
+    /// Returns a deadline of the current time.
+    pub fn now() -> Self {
+        Self::from_instant(Instant::now())
+    }
+
+    /// Returns a deadline `duration` after the current time.
+    ///
+    /// Saturates at the max representable deadline, ~584 years after the epoch.
+    pub fn after(duration: Duration) -> Self {
+        let Deadline(now) = Self::now();
+        let duration = Nanoseconds::try_from(duration.as_nanos()).unwrap_or(Nanoseconds::MAX);
+        Self(now.saturating_add(duration))
+    }
+
+    /// Returns a deadline for the given `Instant`.
+    ///
+    /// Instants before the epoch, which is captured on first use, are clamped to the epoch.
+    pub fn from_instant(instant: Instant) -> Self {
+        let nanos = instant.saturating_duration_since(epoch()).as_nanos();
+        Self(nanos.try_into().unwrap_or(Nanoseconds::MAX))
+    }
+
+    /// Returns the `Instant` of this deadline.
+    pub fn to_instant(self) -> Instant {
+        epoch() + Duration::from_nanos(self.0)
+    }
+
+    /// Returns a deadline from nanoseconds since the epoch, see [`Deadline::as_nanos`].
+    pub fn from_nanos(nanos: Nanoseconds) -> Self {
+        Self(nanos)
+    }
+
+    /// Returns the nanoseconds since the epoch.
+    pub fn as_nanos(self) -> Nanoseconds {
+        self.0
+    }
+
+    /// Returns `true` if the current time is at or after this deadline.
+    pub fn is_past(self) -> bool {
+        Self::now() >= self
+    }
+
+    /// Returns the duration until this deadline, zero if already past.
+    pub fn remaining(self) -> Duration {
+        Duration::from_nanos(self.0.saturating_sub(Self::now().0))
+    }
+}
+
+impl SpinSleeper {
+    /// Puts the [current thread to sleep](fn.native_sleep.html) until the `deadline` less
+    /// the configured native accuracy. Then spins until the specified deadline is reached.
+    ///
+    /// Equivalent to `sleep_until(deadline.to_instant())`.
+    pub fn sleep_to(self, deadline: Deadline) {
+        self.sleep_until(deadline.to_instant());
+    }
+}
+
+/// Returns the crate-managed epoch of [`Deadline`], captured on first call.
+fn epoch() -> Instant {
+    static EPOCH: OnceLock<Instant> = OnceLock::new();
+    *EPOCH.get_or_init(Instant::now)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn after_ordering() {
+        let a = Deadline::after(Duration::from_millis(1));
+        let b = Deadline::after(Duration::from_secs(1));
+        assert!(a < b);
+        assert!(!b.is_past());
+        assert!(b.remaining() > Duration::from_millis(900));
+    }
+
+    #[test]
+    fn instant_round_trip() {
+        let instant = Instant::now() + Duration::from_millis(5);
+        let deadline = Deadline::from_instant(instant);
+        assert_eq!(deadline.to_instant(), instant);
+        assert_eq!(Deadline::from_nanos(deadline.as_nanos()), deadline);
+    }
+
+    #[test]
+    fn sleep_to() {
+        let deadline = Deadline::after(Duration::from_millis(1));
+        SpinSleeper::default().sleep_to(deadline);
+        assert!(deadline.is_past());
+        assert_eq!(deadline.remaining(), Duration::ZERO);
+    }
+}
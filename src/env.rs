@@ -0,0 +1,71 @@
+use super::*;
+use std::{env, sync::Once};
+
+/// Environment variable read by [`SpinSleeper::from_env`] for the native accuracy in nanoseconds.
+const ACCURACY_VAR: &str = "SPIN_SLEEP_ACCURACY_NS";
+/// Environment variable read by [`SpinSleeper::from_env`] for the spin strategy.
+const STRATEGY_VAR: &str = "SPIN_SLEEP_STRATEGY";
+
+impl SpinSleeper {
+    /// Constructs a new SpinSleeper configured by environment variables, falling back to
+    /// [`SpinSleeper::default`] values when unset.
+    ///
+    /// * `SPIN_SLEEP_ACCURACY_NS` native accuracy in nanoseconds, e.g. `100000`.
+    /// * `SPIN_SLEEP_STRATEGY` spin strategy `yield`, `spin` or `none`.
+    ///
+    /// Invalid values are ignored, printing a warning to stderr once.
+    ///
+    /// This is intended for quick experimentation, e.g. comparing settings without recompiling.
+    pub fn from_env() -> Self {
+        let mut sleeper = Self::default();
+
+        if let Ok(accuracy) = env::var(ACCURACY_VAR) {
+            match accuracy.trim().parse() {
+                Ok(ns) => sleeper.native_accuracy_ns = ns,
+                Err(_) => {
+                    static WARN: Once = Once::new();
+                    WARN.call_once(|| {
+                        eprintln!("spin_sleep: ignoring invalid {ACCURACY_VAR}={accuracy:?}")
+                    });
+                }
+            }
+        }
+
+        if let Ok(strategy) = env::var(STRATEGY_VAR) {
+            match parse_strategy(&strategy) {
+                Some(strategy) => sleeper.spin_strategy = strategy,
+                None => {
+                    static WARN: Once = Once::new();
+                    WARN.call_once(|| {
+                        eprintln!("spin_sleep: ignoring invalid {STRATEGY_VAR}={strategy:?}")
+                    });
+                }
+            }
+        }
+
+        sleeper
+    }
+}
+
+fn parse_strategy(s: &str) -> Option<SpinStrategy> {
+    match s.trim() {
+        "yield" => Some(SpinStrategy::YieldThread),
+        "spin" => Some(SpinStrategy::SpinLoopHint),
+        "none" => Some(SpinStrategy::None),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_strategies() {
+        assert_eq!(parse_strategy("yield"), Some(SpinStrategy::YieldThread));
+        assert_eq!(parse_strategy("spin"), Some(SpinStrategy::SpinLoopHint));
+        assert_eq!(parse_strategy(" spin\n"), Some(SpinStrategy::SpinLoopHint));
+        assert_eq!(parse_strategy("none"), Some(SpinStrategy::None));
+        assert_eq!(parse_strategy("fast"), None);
+    }
+}
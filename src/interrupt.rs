@@ -0,0 +1,258 @@
+use super::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Max native sleep between cancel checks in [`SpinSleeper::sleep_until_interruptible`].
+const NATIVE_CHECK_INTERVAL: Duration = Duration::from_millis(1);
+/// Max spin between cancel checks in [`SpinSleeper::sleep_until_interruptible`].
+const SPIN_CHECK_INTERVAL: Duration = Duration::from_micros(10);
+
+/// How an interruptible sleep ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SleepEnd {
+    /// The full sleep completed.
+    Completed {
+        /// Time elapsed beyond the deadline when the sleep completed, measured on waking.
+        overshoot: Duration,
+    },
+    /// The sleep was interrupted before completing.
+    Interrupted {
+        /// Time elapsed since the sleep started.
+        elapsed: Duration,
+    },
+}
+
+impl SleepEnd {
+    /// Returns `true` if the full sleep completed, `false` if interrupted.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep::SpinSleeper;
+    /// use std::sync::atomic::AtomicBool;
+    /// # use std::time::Duration;
+    ///
+    /// let new_work = AtomicBool::new(false);
+    /// let slept_fully = SpinSleeper::default()
+    ///     .sleep_interruptible(Duration::from_millis(1), &new_work)
+    ///     .is_completed();
+    /// assert!(slept_fully);
+    /// ```
+    pub fn is_completed(self) -> bool {
+        matches!(self, Self::Completed { .. })
+    }
+}
+
+impl SpinSleeper {
+    /// Sleeps like [`SpinSleeper::sleep`], returning early if `cancel` is set.
+    ///
+    /// Use [`SleepEnd::is_completed`] to check whether the full duration was slept.
+    /// See [`SpinSleeper::sleep_until_interruptible`].
+    ///
+    /// If the deadline, now plus `duration`, is unrepresentable, e.g. `Duration::MAX`,
+    /// natively sleeps, checking `cancel` every 1ms, until interrupted.
+    pub fn sleep_interruptible(self, duration: Duration, cancel: &AtomicBool) -> SleepEnd {
+        let start = Instant::now();
+        if let Some(deadline) = start.checked_add(duration) {
+            return self.sleep_until_interruptible(deadline, cancel);
+        }
+        loop {
+            if cancel.load(Ordering::Acquire) {
+                return SleepEnd::Interrupted {
+                    elapsed: start.elapsed(),
+                };
+            }
+            self.native_sleep(NATIVE_CHECK_INTERVAL);
+        }
+    }
+
+    /// Sleeps like [`SpinSleeper::sleep_until`], returning early if `cancel` is set.
+    ///
+    /// `cancel` is checked before sleeping and then at least every 1ms while natively sleeping
+    /// & every 10µs while spinning. If set returns [`SleepEnd::Interrupted`] with the elapsed
+    /// time, allowing schedules to be accurately recomputed. Otherwise returns
+    /// [`SleepEnd::Completed`] with the overshoot, measured without additional timing calls.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep::{SleepEnd, SpinSleeper};
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// # use std::time::{Duration, Instant};
+    ///
+    /// let shutdown = AtomicBool::new(false);
+    /// let sleeper = SpinSleeper::default();
+    /// let deadline = Instant::now() + Duration::from_millis(5);
+    ///
+    /// assert!(matches!(
+    ///     sleeper.sleep_until_interruptible(deadline, &shutdown),
+    ///     SleepEnd::Completed { .. }
+    /// ));
+    ///
+    /// shutdown.store(true, Ordering::Release);
+    /// assert!(matches!(
+    ///     sleeper.sleep_interruptible(Duration::from_secs(60), &shutdown),
+    ///     SleepEnd::Interrupted { .. }
+    /// ));
+    /// ```
+    pub fn sleep_until_interruptible(self, deadline: Instant, cancel: &AtomicBool) -> SleepEnd {
+        let start = Instant::now();
+        let duration = deadline.saturating_duration_since(start);
+        let native = self.native_portion(duration);
+        let native_end = start + native;
+
+        loop {
+            if cancel.load(Ordering::Acquire) {
+                return SleepEnd::Interrupted {
+                    elapsed: start.elapsed(),
+                };
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return SleepEnd::Completed {
+                    overshoot: now - deadline,
+                };
+            }
+
+            if now < native_end {
+                self.native_sleep((native_end - now).min(NATIVE_CHECK_INTERVAL));
+            } else if native == duration {
+                // pure native sleep, don't spin
+                self.native_sleep((deadline - now).min(NATIVE_CHECK_INTERVAL));
+            } else {
+                self.spin_until((now + SPIN_CHECK_INTERVAL).min(deadline));
+            }
+        }
+    }
+
+    /// Sleeps like [`SpinSleeper::sleep`], parking the thread using [`thread::park_timeout`]
+    /// for the native portion, so a [`Thread::unpark`](thread::Thread::unpark) from another
+    /// thread wakes it early. The tail is then spun as normal.
+    ///
+    /// Returns [`SleepEnd::Interrupted`] if woken before the native portion elapsed.
+    /// As with [`thread::park`] spurious wake ups may also be reported as interrupted.
+    /// An unpark during the spin tail is not observed, but will cause the next park
+    /// to return immediately.
+    ///
+    /// If the deadline, now plus `duration`, is unrepresentable, e.g. `Duration::MAX`,
+    /// parks until woken, returning [`SleepEnd::Interrupted`].
+    ///
+    /// Useful for precise condvar-like primitives, e.g. a scheduler sleeping until the next
+    /// task deadline that must wake early when an earlier task is enqueued.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep::{SleepEnd, SpinSleeper};
+    /// use std::thread;
+    /// # use std::time::Duration;
+    ///
+    /// let scheduler = thread::spawn(|| {
+    ///     SpinSleeper::default().park_timeout(Duration::from_secs(60))
+    /// });
+    /// # thread::sleep(Duration::from_millis(5));
+    /// scheduler.thread().unpark();
+    /// assert!(matches!(scheduler.join().unwrap(), SleepEnd::Interrupted { .. }));
+    /// ```
+    pub fn park_timeout(self, duration: Duration) -> SleepEnd {
+        let start = Instant::now();
+        let Some(deadline) = start.checked_add(duration) else {
+            // the timeout cannot practically elapse, so any wake up is an interruption
+            thread::park_timeout(duration);
+            return SleepEnd::Interrupted {
+                elapsed: start.elapsed(),
+            };
+        };
+        let native = self.native_portion(duration);
+        // native <= duration so cannot overflow
+        let park_end = start + native;
+
+        let mut end = start;
+        if !native.is_zero() {
+            thread::park_timeout(native);
+            end = Instant::now();
+            if end < park_end {
+                return SleepEnd::Interrupted {
+                    elapsed: end - start,
+                };
+            }
+        }
+        if native < duration {
+            end = self.spin_until(deadline);
+        }
+        SleepEnd::Completed {
+            overshoot: end.saturating_duration_since(deadline),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::{sync::Arc, thread};
+
+    #[test]
+    fn completed() {
+        let cancel = AtomicBool::new(false);
+        let deadline = Instant::now() + Duration::from_millis(3);
+        let end = SpinSleeper::default().sleep_until_interruptible(deadline, &cancel);
+        assert!(end.is_completed());
+        let before = Instant::now();
+        assert!(before >= deadline);
+        match end {
+            SleepEnd::Completed { overshoot } => assert!(overshoot <= before - deadline),
+            end => panic!("unexpected {end:?}"),
+        }
+    }
+
+    #[test]
+    fn interrupted() {
+        let cancel = Arc::new(AtomicBool::new(false));
+        let canceller = thread::spawn({
+            let cancel = Arc::clone(&cancel);
+            move || {
+                thread::sleep(Duration::from_millis(5));
+                cancel.store(true, Ordering::Release);
+            }
+        });
+
+        let end = SpinSleeper::default().sleep_interruptible(Duration::from_secs(10), &cancel);
+        assert!(!end.is_completed());
+        match end {
+            SleepEnd::Interrupted { elapsed } => {
+                assert!(elapsed >= Duration::from_millis(5));
+                assert!(elapsed < Duration::from_secs(5), "{elapsed:?}");
+            }
+            end => panic!("unexpected {end:?}"),
+        }
+        canceller.join().unwrap();
+    }
+
+    #[test]
+    fn interrupted_unrepresentable_deadline() {
+        let cancel = Arc::new(AtomicBool::new(false));
+        let canceller = thread::spawn({
+            let cancel = Arc::clone(&cancel);
+            move || {
+                thread::sleep(Duration::from_millis(5));
+                cancel.store(true, Ordering::Release);
+            }
+        });
+
+        let end = SpinSleeper::default().sleep_interruptible(Duration::MAX, &cancel);
+        assert!(!end.is_completed());
+        canceller.join().unwrap();
+    }
+
+    #[test]
+    fn park_timeout_unrepresentable_deadline() {
+        let parked = thread::spawn(|| SpinSleeper::default().park_timeout(Duration::MAX));
+        thread::sleep(Duration::from_millis(5));
+        parked.thread().unpark();
+        assert!(!parked.join().unwrap().is_completed());
+    }
+
+    #[test]
+    fn park_timeout_completed() {
+        let duration = Duration::from_millis(3);
+        let start = Instant::now();
+        assert!(SpinSleeper::default().park_timeout(duration).is_completed());
+        assert!(start.elapsed() >= duration);
+    }
+}
@@ -0,0 +1,97 @@
+use super::*;
+
+impl SpinSleeper {
+    /// Sleeps, like [`SpinSleeper::sleep`], for a pseudo-random uniformly distributed duration
+    /// in the inclusive range `min..=max`.
+    ///
+    /// The duration is picked using a xorshift generator, advancing the given `seed`.
+    /// So the same initial seed will produce the same sequence of durations.
+    /// A zero seed is replaced by a fixed non-zero value.
+    ///
+    /// Useful for spreading out retries or simulating variable work.
+    ///
+    /// # Panics
+    /// Panics if `min > max`.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::time::Duration;
+    /// let sleeper = spin_sleep::SpinSleeper::default();
+    /// let mut seed = 42;
+    ///
+    /// // sleep for 1-2ms
+    /// sleeper.sleep_jittered(Duration::from_millis(1), Duration::from_millis(2), &mut seed);
+    /// ```
+    #[track_caller]
+    pub fn sleep_jittered(self, min: Duration, max: Duration, seed: &mut u64) {
+        self.sleep(jittered_duration(min, max, seed));
+    }
+}
+
+/// Returns a uniformly distributed duration in `min..=max` advancing the xorshift `seed`.
+#[track_caller]
+fn jittered_duration(min: Duration, max: Duration, seed: &mut u64) -> Duration {
+    assert!(min <= max, "min {min:?} > max {max:?}");
+
+    if *seed == 0 {
+        *seed = 0x9E37_79B9_7F4A_7C15;
+    }
+    // xorshift64
+    *seed ^= *seed << 13;
+    *seed ^= *seed >> 7;
+    *seed ^= *seed << 17;
+
+    let range = u64::try_from((max - min).as_nanos())
+        .unwrap_or(u64::MAX)
+        .saturating_add(1);
+    // map to 0..range by multiply-shift, avoiding modulo bias
+    let offset = ((u128::from(*seed) * u128::from(range)) >> 64) as u64;
+    min + Duration::from_nanos(offset)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn min_eq_max() {
+        let d = Duration::from_micros(123);
+        let mut seed = 1;
+        for _ in 0..100 {
+            assert_eq!(jittered_duration(d, d, &mut seed), d);
+        }
+    }
+
+    #[test]
+    fn deterministic() {
+        let (min, max) = (Duration::ZERO, Duration::from_secs(1));
+        let (mut a, mut b) = (7, 7);
+        for _ in 0..100 {
+            assert_eq!(
+                jittered_duration(min, max, &mut a),
+                jittered_duration(min, max, &mut b)
+            );
+        }
+    }
+
+    #[test]
+    fn uniform() {
+        let (min, max) = (Duration::from_millis(10), Duration::from_millis(20));
+        let mut seed = 0;
+        let mut buckets = [0u32; 10];
+        for _ in 0..100_000 {
+            let d = jittered_duration(min, max, &mut seed);
+            assert!(d >= min && d <= max, "{d:?}");
+            buckets[((d - min).as_nanos() * 10 / (max - min).as_nanos()).min(9) as usize] += 1;
+        }
+        for count in buckets {
+            assert!((9_500..=10_500).contains(&count), "{buckets:?}");
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn min_gt_max() {
+        jittered_duration(Duration::from_secs(2), Duration::from_secs(1), &mut 1);
+    }
+}
@@ -47,16 +47,109 @@
 //! let sleeper = SpinSleeper::default();
 //! # let _ = sleeper;
 //! ```
+//!
+//! # Timing guarantees
+//! * [`SpinSleeper::sleep`] never returns before the duration has elapsed.
+//! * [`SpinSleeper::sleep_until`] never returns before the deadline.
+//! * [`MissedTickBehavior::Skip`] keeps ticks on the period grid.
+//! * [`MissedTickBehavior::Delay`] never schedules a tick less than a period after
+//!   a missed tick is handled.
+//!
+//! The exception is [`SpinSleeper::with_clock_debias`], which may return up to half a
+//! [`clock_resolution`] early. These invariants are covered by deterministic randomized tests
+//! using a mock [`SleepBackend`] clock.
+//!
+//! # `no_std`
+//! Disabling the default **std** feature makes the crate `no_std`. A [`SleepBackend`] providing
+//! a native sleep/delay & clock may then be used with [`SpinSleeper::sleep_with_backend`].
+//! Where there is no native sleep at all, [`SpinSleeper::spin_with_clock`] purely spins
+//! against a user [`Clock`].
+//!
+//! # Tracing
+//! The optional **tracing** feature wraps [`SpinSleeper::sleep`] & [`SpinSleeper::sleep_until`]
+//! in `trace` level `spin_sleep` spans with `requested`, `native`, `spin` & `overshoot` fields.
+//! A `trace` event with the same fields is also emitted after each sleep.
+//! When `trace` level is disabled the overhead is a single cached check.
+//!
+//! # Metrics
+//! The optional **metrics** feature records the overshoot of each [`SpinSleeper::sleep`] &
+//! [`SpinSleeper::sleep_until`] into a `spin_sleep.overshoot_ns` histogram & increments a
+//! `spin_sleep.sleeps` counter using the _metrics_ crate. The name prefix may be configured
+//! with `SpinSleeper::with_metrics_prefix`.
+//!
+//! # WebAssembly
+//! On `wasm32-unknown-unknown` std's [`Instant::now`] & [`thread::sleep`] panic, so
+//! [`SpinSleeper::sleep`] & other `Instant` based functionality cannot be used. Instead
+//! [`SpinSleeper::sleep_with_clock`] & [`SpinSleeper::sleep_until_with_clock`] may be used
+//! with a [`Clock`] reading e.g. `performance.now()`. On this target these spin the native
+//! portion too, so purely spin against the clock, which is still useful for e.g. fixed-step
+//! simulation in the browser.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "std")]
+mod accuracy;
+#[cfg(feature = "std")]
+mod adaptive;
+#[cfg(feature = "std")]
+mod audio;
+mod backend;
+#[cfg(feature = "std")]
+mod bench;
+#[cfg(feature = "std")]
+mod budget;
+mod builder;
+#[cfg(feature = "std")]
+mod channel;
+mod clock;
+#[cfg(feature = "std")]
+mod compensated;
+#[cfg(feature = "std")]
+mod deadline;
+#[cfg(feature = "std")]
+mod env;
+#[cfg(feature = "std")]
+mod interrupt;
+#[cfg(feature = "std")]
+mod jitter;
+#[cfg(all(target_os = "linux", not(miri), feature = "std"))]
+pub mod linux;
+#[cfg(feature = "std")]
 mod loop_helper;
-#[cfg(windows)]
-mod windows;
+#[cfg(all(target_os = "macos", not(miri), feature = "std"))]
+pub mod mac;
+#[cfg(feature = "metrics")]
+mod metric;
+#[cfg(feature = "std")]
+mod missed_tick;
+#[cfg(feature = "std")]
+mod monitored;
+#[cfg(feature = "std")]
+mod poll;
+#[cfg(feature = "std")]
+mod sleep_duration;
+#[cfg(feature = "std")]
+mod stats;
+#[cfg(feature = "std")]
+mod suspend;
+#[cfg(feature = "tracing")]
+mod trace;
+#[cfg(all(windows, not(miri), feature = "std"))]
+pub mod windows;
 
-pub use crate::loop_helper::*;
+#[cfg(feature = "std")]
+pub use crate::{
+    accuracy::*, adaptive::*, audio::*, bench::*, channel::*, compensated::*, deadline::*,
+    interrupt::*, loop_helper::*, missed_tick::*, monitored::*, poll::*, sleep_duration::*,
+    stats::*, suspend::*,
+};
+pub use crate::{backend::*, builder::*, clock::*};
 
-use std::{
-    thread,
-    time::{Duration, Instant},
+use core::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
 };
+#[cfg(feature = "std")]
+use std::{sync::OnceLock, thread, time::Instant};
 
 /// Marker alias to show the meaning of a `f64` in certain methods.
 pub type Seconds = f64;
@@ -72,9 +165,39 @@ pub type SubsecondNanoseconds = u32;
 pub struct SpinSleeper {
     native_accuracy_ns: u32,
     spin_strategy: SpinStrategy,
+    clock_debias: bool,
+    windows_timer_period: bool,
+    max_spins: Option<u64>,
+    spin_threshold: Option<Duration>,
+    raw_qpc: bool,
+    spin_fraction: Option<Fraction>,
+    native_chunking: bool,
+    #[cfg(feature = "metrics")]
+    metric_names: &'static metric::MetricNames,
 }
 
-#[cfg(not(windows))]
+/// `f64` in `0..=1` with bitwise `Eq` & `Hash`.
+#[derive(Debug, Clone, Copy)]
+struct Fraction(f64);
+
+impl PartialEq for Fraction {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+
+impl Eq for Fraction {}
+
+impl core::hash::Hash for Fraction {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+/// Native sleep duration used to yield the core after [`SpinSleeper::with_max_spins`] spins.
+const MAX_SPINS_NATIVE_SLEEP: Duration = Duration::from_micros(1);
+
+#[cfg(not(all(windows, not(miri), feature = "std")))]
 const DEFAULT_NATIVE_SLEEP_ACCURACY: SubsecondNanoseconds = 125_000;
 
 /// Asks the OS to put the current thread to sleep for at least the specified amount of time.
@@ -84,25 +207,130 @@ const DEFAULT_NATIVE_SLEEP_ACCURACY: SubsecondNanoseconds = 125_000;
 /// * **Windows** (>= Windows 10, version 1803): Uses a high resolution waitable timer, similar to std in rust >= 1.75.
 /// * **Windows** (< Windows 10, version 1803): Automatically selects the best native sleep accuracy
 ///   generally achieving ~1ms native sleep accuracy, instead of default ~16ms.
+#[cfg(feature = "std")]
 #[inline]
 pub fn native_sleep(duration: Duration) {
-    #[cfg(windows)]
-    windows::native_sleep(duration);
+    #[cfg(all(windows, not(miri)))]
+    windows::native_sleep(duration, true);
 
-    #[cfg(not(windows))]
+    #[cfg(any(not(windows), miri))]
     thread::sleep(duration);
 }
 
+/// Spins, using the given [`SpinStrategy`], until the specified `deadline`.
+/// **Does not natively sleep.**
+///
+/// This is the spinning complement to [`native_sleep`], which may be used to build custom
+/// sleeping schemes or where system calls must be avoided. Returns immediately if
+/// the deadline has passed.
+#[cfg(feature = "std")]
+#[inline]
+pub fn busy_wait_until(deadline: Instant, strategy: SpinStrategy) {
+    busy_wait_until_observed(deadline, strategy, &mut ());
+}
+
+/// [`busy_wait_until`] notifying the `observer` of each spin.
+///
+/// Returns the final clock reading, at or after the `deadline`.
+#[cfg(feature = "std")]
+#[inline]
+fn busy_wait_until_observed<O: SleepObserver>(
+    deadline: Instant,
+    strategy: SpinStrategy,
+    observer: &mut O,
+) -> Instant {
+    loop {
+        let now = Instant::now();
+        if now >= deadline {
+            return now;
+        }
+        strategy.spin(deadline - now);
+        observer.spin();
+    }
+}
+
+/// Spins, using the given [`SpinStrategy`], until the `duration` has elapsed.
+/// **Does not natively sleep.**
+///
+/// Convenience function for `busy_wait_until(Instant::now() + duration, strategy)`.
+#[cfg(feature = "std")]
+#[inline]
+pub fn busy_wait(duration: Duration, strategy: SpinStrategy) {
+    busy_wait_until(Instant::now() + duration, strategy);
+}
+
+/// Spins, using the default [`SpinStrategy`], until the `duration` has elapsed.
+/// **Never natively sleeps**, so achieves maximum accuracy regardless of CPU cost.
+///
+/// Note this differs from `SpinSleeper::new(0)`, which trusts native sleep completely &
+/// never spins.
+///
+/// # Example
+/// ```
+/// # use std::time::{Duration, Instant};
+/// let start = Instant::now();
+/// spin_sleep::spin(Duration::from_micros(100));
+/// assert!(start.elapsed() >= Duration::from_micros(100));
+/// ```
+#[cfg(feature = "std")]
+#[inline]
+pub fn spin(duration: Duration) {
+    busy_wait(duration, SpinStrategy::default());
+}
+
+/// Spins, using the default [`SpinStrategy`], until the specified `deadline`.
+/// **Never natively sleeps.** Returns immediately if the deadline has passed.
+///
+/// See [`spin`].
+#[cfg(feature = "std")]
+#[inline]
+pub fn spin_until(deadline: Instant) {
+    busy_wait_until(deadline, SpinStrategy::default());
+}
+
+/// Global default native accuracy set by [`set_default_native_accuracy_ns`],
+/// [`UNSET_DEFAULT_ACCURACY`] if not set.
+static DEFAULT_ACCURACY: AtomicU32 = AtomicU32::new(UNSET_DEFAULT_ACCURACY);
+const UNSET_DEFAULT_ACCURACY: SubsecondNanoseconds = u32::MAX;
+
+/// Sets the global default native accuracy used by [`SpinSleeper::default`], and so
+/// also by [`sleep`] & other defaulted sleepers.
+///
+/// Allows tuning, e.g. after measuring the machine at startup, in a single place.
+/// Setting `u32::MAX` restores the OS specific default.
+///
+/// # Example
+/// ```
+/// use spin_sleep::SpinSleeper;
+///
+/// spin_sleep::set_default_native_accuracy_ns(200_000);
+/// assert_eq!(SpinSleeper::default().native_accuracy_ns(), 200_000);
+/// # spin_sleep::set_default_native_accuracy_ns(u32::MAX);
+/// ```
+pub fn set_default_native_accuracy_ns(accuracy: SubsecondNanoseconds) {
+    DEFAULT_ACCURACY.store(accuracy, Ordering::Relaxed);
+}
+
+/// Returns the native accuracy used by [`SpinSleeper::default`]. This is the global
+/// default set by [`set_default_native_accuracy_ns`], or a default suiting the current OS.
+pub fn default_native_accuracy_ns() -> SubsecondNanoseconds {
+    match DEFAULT_ACCURACY.load(Ordering::Relaxed) {
+        UNSET_DEFAULT_ACCURACY => {
+            #[cfg(all(windows, not(miri), feature = "std"))]
+            return windows::sleep_accuracy();
+            #[cfg(not(all(windows, not(miri), feature = "std")))]
+            DEFAULT_NATIVE_SLEEP_ACCURACY
+        }
+        accuracy => accuracy,
+    }
+}
+
 impl Default for SpinSleeper {
-    /// Constructs new SpinSleeper with defaults suiting the current OS
+    /// Constructs new SpinSleeper with defaults suiting the current OS, or the
+    /// global default native accuracy, see [`set_default_native_accuracy_ns`].
     #[inline]
     fn default() -> Self {
-        #[cfg(windows)]
-        let accuracy = windows::sleep_accuracy();
-        #[cfg(not(windows))]
-        let accuracy = DEFAULT_NATIVE_SLEEP_ACCURACY;
-
-        SpinSleeper::new(accuracy)
+        SpinSleeper::new(default_native_accuracy_ns())
     }
 }
 
@@ -110,21 +338,102 @@ impl SpinSleeper {
     /// Constructs new SpinSleeper with the input native sleep accuracy.
     /// The lower the `native_accuracy_ns` the more we effectively trust the accuracy of the
     /// [`native_sleep`] function.
+    ///
+    /// `SpinSleeper::new(0)` trusts native sleep completely and is equivalent to
+    /// [`native_sleep`], it will never spin.
+    ///
+    /// This is a `const fn` so may be used to declare static sleepers.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep::{SpinSleeper, SpinStrategy};
+    ///
+    /// static SLEEPER: SpinSleeper =
+    ///     SpinSleeper::new(100_000).with_spin_strategy(SpinStrategy::SpinLoopHint);
+    /// # assert_eq!(SLEEPER.native_accuracy_ns(), 100_000);
+    /// ```
     #[inline]
-    pub fn new(native_accuracy_ns: SubsecondNanoseconds) -> SpinSleeper {
+    pub const fn new(native_accuracy_ns: SubsecondNanoseconds) -> SpinSleeper {
         SpinSleeper {
             native_accuracy_ns,
-            spin_strategy: <_>::default(),
+            spin_strategy: SpinStrategy::os_default(),
+            clock_debias: false,
+            windows_timer_period: true,
+            max_spins: None,
+            spin_threshold: None,
+            raw_qpc: false,
+            spin_fraction: None,
+            native_chunking: false,
+            #[cfg(feature = "metrics")]
+            metric_names: &metric::DEFAULT_NAMES,
         }
     }
 
+    /// Constructs new SpinSleeper that natively sleeps as much as possible while keeping
+    /// sleep overshoot within the `target`.
+    ///
+    /// The native accuracy is set to the worst observed [`native_sleep`] overshoot less the
+    /// `target`. Native sleep overshoot is measured once, taking a few tens of milliseconds,
+    /// on first call and cached.
+    ///
+    /// Note: Native sleep accuracy may vary with system load & state so this is not a guarantee.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep::SpinSleeper;
+    /// # use std::time::Duration;
+    ///
+    /// let sleeper = SpinSleeper::for_max_overshoot(Duration::from_micros(10));
+    ///
+    /// // a large target means native sleep can be fully trusted
+    /// let lax = SpinSleeper::for_max_overshoot(Duration::from_secs(1));
+    /// assert_eq!(lax.native_accuracy_ns(), 0);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn for_max_overshoot(target: Duration) -> SpinSleeper {
+        let accuracy = native_sleep_max_overshoot().saturating_sub(target);
+        SpinSleeper::new(accuracy.as_nanos().try_into().unwrap_or(u32::MAX))
+    }
+
     /// Returns configured native_accuracy_ns.
-    pub fn native_accuracy_ns(self) -> SubsecondNanoseconds {
+    pub const fn native_accuracy_ns(self) -> SubsecondNanoseconds {
         self.native_accuracy_ns
     }
 
+    /// Returns configured native accuracy as a `Duration`.
+    pub const fn native_accuracy(self) -> Duration {
+        Duration::from_nanos(self.native_accuracy_ns as u64)
+    }
+
+    /// Returns a spin sleeper with the given native accuracy,
+    /// see [`SpinSleeper::new`].
+    ///
+    /// Saturates at `u32::MAX` nanoseconds, ~4.29s.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep::SpinSleeper;
+    /// # use std::time::Duration;
+    ///
+    /// let sleeper = SpinSleeper::default().with_native_accuracy(Duration::from_micros(500));
+    /// assert_eq!(sleeper.native_accuracy_ns(), 500_000);
+    /// assert_eq!(sleeper.native_accuracy(), Duration::from_micros(500));
+    ///
+    /// let sleeper = sleeper.with_native_accuracy(Duration::from_secs(5));
+    /// assert_eq!(sleeper.native_accuracy_ns(), u32::MAX);
+    /// ```
+    pub const fn with_native_accuracy(mut self, accuracy: Duration) -> Self {
+        let nanos = accuracy.as_nanos();
+        self.native_accuracy_ns = if nanos > u32::MAX as u128 {
+            u32::MAX
+        } else {
+            nanos as u32
+        };
+        self
+    }
+
     /// Returns configured spin strategy.
-    pub fn spin_strategy(self) -> SpinStrategy {
+    pub const fn spin_strategy(self) -> SpinStrategy {
         self.spin_strategy
     }
 
@@ -136,54 +445,578 @@ impl SpinSleeper {
     ///
     /// let sleeper = SpinSleeper::default().with_spin_strategy(SpinStrategy::SpinLoopHint);
     /// ```
-    pub fn with_spin_strategy(mut self, strategy: SpinStrategy) -> Self {
+    pub const fn with_spin_strategy(mut self, strategy: SpinStrategy) -> Self {
         self.spin_strategy = strategy;
         self
     }
 
+    /// Returns whether spin exit clock de-biasing is enabled.
+    pub const fn clock_debias(self) -> bool {
+        self.clock_debias
+    }
+
+    /// Returns a spin sleeper with spin exit clock de-biasing enabled or disabled.
+    ///
+    /// Spinning exits on the first `Instant::now()` reading at or after the deadline.
+    /// On platforms where the clock advances in coarse steps this means sleeps deterministically
+    /// land on the next clock edge, i.e. late by half a clock tick on average.
+    /// When enabled, the spin exits half a [`clock_resolution`] early so results are
+    /// centered around the true deadline. Note this means sleeps may return slightly early.
+    ///
+    /// This is a fine-tuning only useful for coarse-clock platforms. Default `false`.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep::SpinSleeper;
+    /// # use std::time::Duration;
+    ///
+    /// let coarse_clock = spin_sleep::clock_resolution() >= Duration::from_micros(1);
+    /// let sleeper = SpinSleeper::default().with_clock_debias(coarse_clock);
+    /// ```
+    pub const fn with_clock_debias(mut self, debias: bool) -> Self {
+        self.clock_debias = debias;
+        self
+    }
+
+    /// Returns whether the Windows fallback native sleep may change the global timer period.
+    pub const fn windows_timer_period(self) -> bool {
+        self.windows_timer_period
+    }
+
+    /// Returns a spin sleeper that may, or may not, change the global timer period
+    /// on Windows versions without high resolution waitable timers (< Windows 10, version 1803).
+    ///
+    /// When `false` the fallback native sleep will not call `timeBeginPeriod`/`timeEndPeriod`,
+    /// avoiding the process-wide side effect. Native sleep will then generally only achieve ~16ms
+    /// accuracy so the native accuracy should be increased accordingly, relying more on spinning.
+    ///
+    /// To instead avoid calling these per sleep, hold a `windows::TimerResolutionGuard`
+    /// for the duration of a sleep loop.
+    ///
+    /// Has no effect on other platforms. Default `true`.
+    pub const fn with_windows_timer_period(mut self, enabled: bool) -> Self {
+        self.windows_timer_period = enabled;
+        self
+    }
+
+    /// Returns the configured maximum consecutive spins, see [`SpinSleeper::with_max_spins`].
+    pub const fn max_spins(self) -> Option<u64> {
+        self.max_spins
+    }
+
+    /// Returns a spin sleeper that spins at most `max_spins` consecutive iterations before
+    /// performing a short native sleep, then resuming spinning.
+    ///
+    /// This is a robustness guard for unreliable clock sources, e.g. buggy hypervisors where
+    /// the monotonic clock may stall, guaranteeing the core is periodically yielded rather
+    /// than hard-hanging. Should be set well above the spins normally required
+    /// for the native accuracy.
+    ///
+    /// Default `None`, no limit.
+    pub const fn with_max_spins(mut self, max_spins: u64) -> Self {
+        self.max_spins = Some(max_spins);
+        self
+    }
+
+    /// Returns whether spinning reads `QueryPerformanceCounter` directly on Windows,
+    /// see [`SpinSleeper::with_raw_qpc`].
+    pub const fn raw_qpc(self) -> bool {
+        self.raw_qpc
+    }
+
+    /// Returns a spin sleeper that, on Windows, spins reading `QueryPerformanceCounter`
+    /// directly instead of `Instant::now()`, reducing per-iteration overhead.
+    ///
+    /// This only changes the clock used for the spin portion, native sleep still uses the
+    /// waitable timer. Not used in combination with [`SpinSleeper::with_max_spins`].
+    ///
+    /// Has no effect on other platforms. Default `false`.
+    pub const fn with_raw_qpc(mut self, enabled: bool) -> Self {
+        self.raw_qpc = enabled;
+        self
+    }
+
+    /// Returns whether the native portion is slept in two stages,
+    /// see [`SpinSleeper::with_native_chunking`].
+    pub const fn native_chunking(self) -> bool {
+        self.native_chunking
+    }
+
+    /// Returns a spin sleeper that natively sleeps in two stages. First sleeping most of the
+    /// native portion, then re-measuring & natively sleeping whatever remains above the
+    /// native accuracy, only then spinning.
+    ///
+    /// This trades an extra native sleep call for tighter worst-case overshoot, since the
+    /// second shorter native sleep is less likely to overshoot badly, e.g. under load.
+    ///
+    /// Default `false`.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep::SpinSleeper;
+    /// # use std::time::Duration;
+    ///
+    /// let sleeper = SpinSleeper::default().with_native_chunking(true);
+    /// let elapsed = sleeper.sleep(Duration::from_millis(5));
+    /// assert!(elapsed >= Duration::from_millis(5));
+    /// ```
+    pub const fn with_native_chunking(mut self, enabled: bool) -> Self {
+        self.native_chunking = enabled;
+        self
+    }
+
+    /// Returns the configured spin threshold, see [`SpinSleeper::with_spin_threshold`].
+    pub const fn spin_threshold(self) -> Option<Duration> {
+        self.spin_threshold
+    }
+
+    /// Returns a spin sleeper that only natively sleeps, never spinning, for sleep
+    /// durations larger than the `threshold`.
+    ///
+    /// Useful to avoid needless spinning on long sleeps where high accuracy is not required.
+    /// Sleeps at or below the threshold use the native accuracy as normal.
+    ///
+    /// Default `None`, always spin.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep::SpinSleeper;
+    /// # use std::time::Duration;
+    ///
+    /// let sleeper = SpinSleeper::new(100_000).with_spin_threshold(Duration::from_secs(1));
+    /// assert_eq!(
+    ///     sleeper.native_portion(Duration::from_secs(10)),
+    ///     Duration::from_secs(10)
+    /// );
+    /// ```
+    pub const fn with_spin_threshold(mut self, threshold: Duration) -> Self {
+        self.spin_threshold = Some(threshold);
+        self
+    }
+
+    /// Returns the configured spin fraction, see [`SpinSleeper::with_spin_fraction`].
+    pub fn spin_fraction(self) -> Option<f64> {
+        self.spin_fraction.map(|f| f.0)
+    }
+
+    /// Returns a spin sleeper that spins the final `fraction` of each sleep duration,
+    /// natively sleeping the rest. The spin portion is capped by the native accuracy,
+    /// so long sleeps spin no more than without a spin fraction.
+    ///
+    /// Default `None`, spin the native accuracy.
+    ///
+    /// # Panics
+    /// Panics if `fraction` is not in the range `0..=1`.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep::SpinSleeper;
+    /// # use std::time::Duration;
+    ///
+    /// // spin the last 10%, at most 500µs
+    /// let sleeper = SpinSleeper::new(500_000).with_spin_fraction(0.1);
+    /// assert_eq!(
+    ///     sleeper.native_portion(Duration::from_millis(1)),
+    ///     Duration::from_micros(900)
+    /// );
+    /// assert_eq!(
+    ///     sleeper.native_portion(Duration::from_millis(100)),
+    ///     Duration::from_micros(99_500)
+    /// );
+    /// ```
+    #[track_caller]
+    pub fn with_spin_fraction(mut self, fraction: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&fraction),
+            "spin fraction must be in 0..=1, got {fraction}"
+        );
+        self.spin_fraction = Some(Fraction(fraction));
+        self
+    }
+
+    /// Returns the portion of the `duration` that would be natively slept by
+    /// [`SpinSleeper::sleep`], the remainder being spun.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep::SpinSleeper;
+    /// # use std::time::Duration;
+    ///
+    /// let sleeper = SpinSleeper::new(100_000);
+    /// assert_eq!(
+    ///     sleeper.native_portion(Duration::from_millis(1)),
+    ///     Duration::from_micros(900)
+    /// );
+    /// assert_eq!(
+    ///     sleeper.native_portion(Duration::from_micros(50)),
+    ///     Duration::ZERO
+    /// );
+    /// ```
+    #[inline]
+    pub fn native_portion(self, duration: Duration) -> Duration {
+        if self.spin_threshold.is_some_and(|t| duration > t) {
+            return duration;
+        }
+        let accuracy = Duration::new(0, self.native_accuracy_ns);
+        let spin = match self.spin_fraction {
+            Some(Fraction(fraction)) => duration.mul_f64(fraction).min(accuracy),
+            None => accuracy,
+        };
+        duration.saturating_sub(spin)
+    }
+
+    /// Native sleep respecting configured platform options.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn native_sleep(self, duration: Duration) {
+        #[cfg(all(windows, not(miri)))]
+        windows::native_sleep(duration, self.windows_timer_period);
+
+        #[cfg(any(not(windows), miri))]
+        thread::sleep(duration);
+    }
+
     /// The internal `spin_sleep` method that puts the [current thread to sleep](fn.native_sleep.html)
     /// for the duration less the configured native accuracy, then spins until the specified deadline.
+    ///
+    /// Returns the time the sleep ended, read once on waking.
+    #[cfg(feature = "std")]
     #[inline]
-    fn spin_sleep(self, duration: Duration, deadline: Instant) {
-        let accuracy = Duration::new(0, self.native_accuracy_ns);
-        if duration > accuracy {
-            native_sleep(duration - accuracy);
+    fn spin_sleep(self, duration: Duration, deadline: Instant) -> Instant {
+        self.spin_sleep_with(duration, deadline, &mut ())
+    }
+
+    /// [`SpinSleeper::spin_sleep`] notifying the `observer` of the spin phase.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn spin_sleep_with<O: SleepObserver>(
+        self,
+        duration: Duration,
+        deadline: Instant,
+        observer: &mut O,
+    ) -> Instant {
+        // Miri: avoid spinning, which is very slow to interpret
+        if cfg!(miri) {
+            thread::sleep(duration);
+            return Instant::now();
         }
-        // spin the rest of the duration
-        while Instant::now() < deadline {
-            match self.spin_strategy {
-                SpinStrategy::YieldThread => thread::yield_now(),
-                SpinStrategy::SpinLoopHint => std::hint::spin_loop(),
+
+        if self.native_accuracy_ns == 0 {
+            self.native_sleep(duration);
+            return Instant::now();
+        }
+        let native = self.native_portion(duration);
+        if native == duration {
+            self.native_sleep_to(duration, deadline);
+            return Instant::now();
+        }
+        if self.native_chunking {
+            self.chunked_native_sleep(native, duration - native, deadline);
+        } else if !native.is_zero() {
+            self.native_sleep_to(native, deadline - (duration - native));
+        }
+        self.spin_until_with(deadline, observer)
+    }
+
+    /// Natively sleeps for `native`, which should end at `end`.
+    ///
+    /// On Linux sleeps to the absolute `end`, see [`linux::native_sleep_until`].
+    #[cfg(feature = "std")]
+    #[inline]
+    fn native_sleep_to(self, native: Duration, end: Instant) {
+        #[cfg(all(target_os = "linux", not(miri)))]
+        {
+            let _ = native;
+            linux::native_sleep_until(end);
+        }
+
+        #[cfg(not(all(target_os = "linux", not(miri))))]
+        {
+            let _ = end;
+            self.native_sleep(native);
+        }
+    }
+
+    /// Natively sleeps the `native` portion in two stages. First leaving an extra `spin`
+    /// duration, then re-measuring & natively sleeping any remainder above `spin`.
+    #[cfg(feature = "std")]
+    fn chunked_native_sleep(self, native: Duration, spin: Duration, deadline: Instant) {
+        let first = native.saturating_sub(spin);
+        if !first.is_zero() {
+            self.native_sleep(first);
+        }
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining > spin {
+            self.native_sleep(remaining - spin);
+        }
+    }
+
+    /// Spins, using the configured spin options, until the `deadline`.
+    ///
+    /// Returns the final clock reading, at or after the `deadline`.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn spin_until(self, deadline: Instant) -> Instant {
+        self.spin_until_with(deadline, &mut ())
+    }
+
+    /// [`SpinSleeper::spin_until`] notifying the `observer` of the spin phase.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn spin_until_with<O: SleepObserver>(self, deadline: Instant, observer: &mut O) -> Instant {
+        observer.spin_start();
+        if cfg!(miri) {
+            thread::sleep(deadline.saturating_duration_since(Instant::now()));
+            return Instant::now();
+        }
+
+        let deadline = match self.clock_debias {
+            true => deadline
+                .checked_sub(clock_resolution() / 2)
+                .unwrap_or(deadline),
+            false => deadline,
+        };
+        let Some(max_spins) = self.max_spins else {
+            #[cfg(all(windows, not(miri)))]
+            if self.raw_qpc {
+                return windows::busy_wait_until_qpc(deadline, self.spin_strategy, observer);
+            }
+            return busy_wait_until_observed(deadline, self.spin_strategy, observer);
+        };
+
+        let mut spins = 0;
+        loop {
+            let now = Instant::now();
+            if now >= deadline {
+                return now;
+            }
+            if spins < max_spins {
+                self.spin_strategy.spin(deadline - now);
+                observer.spin();
+                spins += 1;
+            } else {
+                self.native_sleep(MAX_SPINS_NATIVE_SLEEP);
+                spins = 0;
             }
         }
     }
 
     /// Puts the [current thread to sleep](fn.native_sleep.html) for the `duration` less the
     /// configured native accuracy. Then spins until the specified duration has elapsed.
-    pub fn sleep(self, duration: Duration) {
-        let deadline = Instant::now() + duration;
-        self.spin_sleep(duration, deadline);
+    ///
+    /// Returns the actual elapsed duration, allowing overshoot to be measured without
+    /// additional timing calls.
+    ///
+    /// If the deadline, now plus `duration`, is unrepresentable, e.g. `Duration::MAX`,
+    /// natively sleeps the full duration without spinning, i.e. effectively forever.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::time::Duration;
+    /// let sleeper = spin_sleep::SpinSleeper::default();
+    /// let elapsed = sleeper.sleep(Duration::from_millis(1));
+    /// assert!(elapsed >= Duration::from_millis(1));
+    /// println!("overshoot {:?}", elapsed - Duration::from_millis(1));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn sleep(self, duration: Duration) -> Duration {
+        let start = Instant::now();
+        let Some(deadline) = start.checked_add(duration) else {
+            self.native_sleep(duration);
+            return start.elapsed();
+        };
+        let elapsed = self.spin_sleep_observed(duration, deadline) - start;
+        #[cfg(feature = "metrics")]
+        self.record_sleep_metrics(elapsed.saturating_sub(duration));
+        elapsed
     }
 
     /// Puts the [current thread to sleep](fn.native_sleep.html) until the `deadline` less
     /// the configured native accuracy. Then spins until the specified deadline is reached.
+    ///
+    /// The current time is read once to compute the remaining duration, so this is more
+    /// accurate than `sleep(deadline - Instant::now())` for deadline-chained loops.
+    /// If the `deadline` is already past returns immediately without sleeping or spinning.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::time::{Duration, Instant};
+    /// let sleeper = spin_sleep::SpinSleeper::default();
+    /// let mut deadline = Instant::now();
+    /// for _ in 0..3 {
+    ///     deadline += Duration::from_millis(2);
+    ///     sleeper.sleep_until(deadline);
+    ///     assert!(Instant::now() >= deadline);
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
     pub fn sleep_until(self, deadline: Instant) {
-        let duration = deadline.saturating_duration_since(Instant::now());
-        self.spin_sleep(duration, deadline);
+        self.sleep_until_with_now(deadline, Instant::now());
+    }
+
+    /// [`SpinSleeper::spin_sleep`], traced with the **tracing** feature when enabled.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn spin_sleep_observed(self, duration: Duration, deadline: Instant) -> Instant {
+        #[cfg(feature = "tracing")]
+        if tracing::enabled!(tracing::Level::TRACE) {
+            return self.spin_sleep_traced(duration, deadline);
+        }
+        self.spin_sleep(duration, deadline)
+    }
+
+    /// Puts the [current thread to sleep](fn.native_sleep.html) until the `deadline` less
+    /// the configured native accuracy. Then spins until the specified deadline is reached.
+    ///
+    /// Equivalent to [`SpinSleeper::sleep_until`] using the given `now` as the current time
+    /// instead of reading the clock. Useful to avoid a redundant clock read when the current
+    /// time is already known, e.g. in high frequency loops.
+    ///
+    /// `now` should be a recent reading of `Instant::now()`. If the `deadline` is not after
+    /// `now` returns immediately without sleeping or spinning.
+    ///
+    /// Returns the elapsed duration since `now`, read once on waking, allowing lateness to
+    /// be measured without additional timing calls. Zero if returned immediately.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::time::{Duration, Instant};
+    /// let sleeper = spin_sleep::SpinSleeper::default();
+    /// let deadline = Instant::now() + Duration::from_micros(100);
+    ///
+    /// let now = Instant::now();
+    /// if now < deadline {
+    ///     sleeper.sleep_until_with_now(deadline, now);
+    /// }
+    /// assert!(Instant::now() >= deadline);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn sleep_until_with_now(self, deadline: Instant, now: Instant) -> Duration {
+        let duration = deadline.saturating_duration_since(now);
+        if duration.is_zero() {
+            return Duration::ZERO;
+        }
+        let end = self.spin_sleep_observed(duration, deadline);
+        #[cfg(feature = "metrics")]
+        self.record_sleep_metrics(end.saturating_duration_since(deadline));
+        end.saturating_duration_since(now)
+    }
+
+    /// Puts the [current thread to sleep](fn.native_sleep.html) until `duration` after `start`
+    /// less the configured native accuracy. Then spins until `start + duration` is reached.
+    ///
+    /// Equivalent to `sleep_until(start + duration)`. Useful for pacing anchored to a known
+    /// start, e.g. a frame start, avoiding the gap of computing the remaining duration.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::time::{Duration, Instant};
+    /// # fn compute_something() {}
+    /// let sleeper = spin_sleep::SpinSleeper::default();
+    ///
+    /// let frame_start = Instant::now();
+    /// compute_something();
+    /// sleeper.sleep_from(frame_start, Duration::from_millis(5));
+    /// assert!(frame_start.elapsed() >= Duration::from_millis(5));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn sleep_from(self, start: Instant, duration: Duration) {
+        self.sleep_until(start + duration);
+    }
+
+    /// Like [`SpinSleeper::sleep_until`] but natively sleeps in `every` sized chunks,
+    /// calling `on_progress` with the remaining duration after each chunk.
+    /// Then precisely sleeps the final section as normal.
+    ///
+    /// If `every` is larger than the remaining time (or zero) this is equivalent to
+    /// [`SpinSleeper::sleep_until`].
+    ///
+    /// # Example
+    /// ```
+    /// # use std::time::{Duration, Instant};
+    /// let sleeper = spin_sleep::SpinSleeper::default();
+    /// let deadline = Instant::now() + Duration::from_millis(30);
+    ///
+    /// sleeper.sleep_until_with_progress(deadline, Duration::from_millis(10), |remaining| {
+    ///     println!("{remaining:?} remaining");
+    /// });
+    /// assert!(Instant::now() >= deadline);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn sleep_until_with_progress(
+        self,
+        deadline: Instant,
+        every: Duration,
+        mut on_progress: impl FnMut(Duration),
+    ) {
+        if !every.is_zero() {
+            let accuracy = Duration::new(0, self.native_accuracy_ns);
+            // only sleep chunks that leave more than the accuracy remaining
+            while deadline.saturating_duration_since(Instant::now()) > every + accuracy {
+                self.native_sleep(every);
+                on_progress(deadline.saturating_duration_since(Instant::now()));
+            }
+        }
+        self.sleep_until(deadline);
     }
 
     /// Puts the [current thread to sleep](fn.native_sleep.html) for the give seconds-duration
     /// less the configured native accuracy. Then spins until the specified duration has elapsed.
-    pub fn sleep_s(self, seconds: Seconds) {
-        if seconds > 0.0 {
-            self.sleep(Duration::from_secs_f64(seconds));
-        }
+    ///
+    /// Returns the actual elapsed duration.
+    ///
+    /// Non-positive & `NaN` seconds return immediately. Infinite, or unrepresentably large,
+    /// seconds natively sleep forever, see [`SpinSleeper::sleep`].
+    #[cfg(feature = "std")]
+    pub fn sleep_s(self, seconds: Seconds) -> Duration {
+        self.sleep_for(seconds)
     }
 
     /// Puts the [current thread to sleep](fn.native_sleep.html) for the give nanoseconds-duration
     /// less the configured native accuracy. Then spins until the specified duration has elapsed.
-    pub fn sleep_ns(self, nanoseconds: Nanoseconds) {
-        self.sleep(Duration::from_nanos(nanoseconds))
+    ///
+    /// Returns the actual elapsed duration.
+    ///
+    /// If the deadline is unrepresentable, see [`SpinSleeper::try_sleep_ns`], natively sleeps
+    /// the full duration without spinning, i.e. effectively forever, instead of panicking.
+    #[cfg(feature = "std")]
+    pub fn sleep_ns(self, nanoseconds: Nanoseconds) -> Duration {
+        self.sleep_for(nanoseconds)
+    }
+
+    /// Like [`SpinSleeper::sleep_ns`] but returns an error, without sleeping, if the
+    /// deadline, the current time plus `nanoseconds`, is unrepresentable by [`Instant`].
+    ///
+    /// Useful to get defined behaviour when passed nonsensical values, e.g. absolute
+    /// timestamps mistakenly used as durations.
+    ///
+    /// # Example
+    /// ```
+    /// let sleeper = spin_sleep::SpinSleeper::default();
+    /// let elapsed = sleeper.try_sleep_ns(1_000_000).expect("sleep");
+    /// assert!(elapsed.as_nanos() >= 1_000_000);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn try_sleep_ns(self, nanoseconds: Nanoseconds) -> Result<Duration, TimeError> {
+        let duration = Duration::from_nanos(nanoseconds);
+        if Instant::now().checked_add(duration).is_none() {
+            return Err(TimeError::Overflow);
+        }
+        Ok(self.sleep(duration))
+    }
+
+    /// Initialises lazy sleep & clock state, e.g. the Windows high resolution timer,
+    /// so the first real sleep is as accurate as following sleeps.
+    ///
+    /// Some state is per-thread so this should be called on the thread that will sleep.
+    /// Performs a minimal native sleep.
+    #[cfg(feature = "std")]
+    pub fn warm_up(self) {
+        if self.clock_debias {
+            clock_resolution();
+        }
+        let _ = Instant::now();
+        self.native_sleep(Duration::from_nanos(1));
     }
 }
 
@@ -192,6 +1025,7 @@ impl SpinSleeper {
 ///
 /// Convenience function for `SpinSleeper::default().sleep(duration)`. Can directly take the
 /// place of `thread::sleep`.
+#[cfg(feature = "std")]
 pub fn sleep(duration: Duration) {
     SpinSleeper::default().sleep(duration);
 }
@@ -201,31 +1035,223 @@ pub fn sleep(duration: Duration) {
 ///
 /// Convenience function for `SpinSleeper::default().sleep_until(instant)`. Can directly take
 /// the place of `thread::sleep_until`.
+#[cfg(feature = "std")]
 pub fn sleep_until(deadline: Instant) {
     SpinSleeper::default().sleep_until(deadline);
 }
 
+/// Returns the smallest observed non-zero step of `Instant::now()`.
+///
+/// This is measured once on first call and cached.
+#[cfg(feature = "std")]
+pub fn clock_resolution() -> Duration {
+    static CLOCK_RESOLUTION: OnceLock<Duration> = OnceLock::new();
+
+    *CLOCK_RESOLUTION.get_or_init(|| {
+        let mut resolution = Duration::MAX;
+        for _ in 0..100 {
+            let start = Instant::now();
+            let mut now = Instant::now();
+            while now == start {
+                now = Instant::now();
+            }
+            resolution = resolution.min(now - start);
+        }
+        resolution
+    })
+}
+
+/// Returns the worst observed [`native_sleep`] overshoot.
+///
+/// This is measured once on first call and cached.
+#[cfg(feature = "std")]
+fn native_sleep_max_overshoot() -> Duration {
+    static MAX_OVERSHOOT: OnceLock<Duration> = OnceLock::new();
+
+    *MAX_OVERSHOOT.get_or_init(|| measure_native_accuracy(20, Duration::from_millis(1)).worst)
+}
+
 /// What to do while spinning.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy)]
 #[non_exhaustive]
 pub enum SpinStrategy {
     /// Call [`std::thread::yield_now`] while spinning.
+    /// Without the **std** feature this is equivalent to `SpinLoopHint`.
     YieldThread,
-    /// Call [`std::hint::spin_loop`] while spinning.
+    /// Call [`core::hint::spin_loop`] while spinning.
     SpinLoopHint,
+    /// Spin without any loop body, only checking the time.
+    ///
+    /// On some platforms this is measurably tighter than other strategies at sub-microsecond
+    /// scales, at the cost of fully occupying a core without hinting the CPU.
+    None,
+    /// Use different strategies depending on the remaining spin time.
+    ///
+    /// Each spin iteration uses the strategy of the first stage with a threshold less than the
+    /// remaining time, so stages must be in **descending threshold order**.
+    /// If no stage matches, spins without any body.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep::{SpinSleeper, SpinStrategy};
+    /// use std::time::Duration;
+    ///
+    /// // Yield while more than 1ms remains, otherwise use spin loop hints
+    /// const STAGES: &[(Duration, SpinStrategy)] = &[
+    ///     (Duration::from_millis(1), SpinStrategy::YieldThread),
+    ///     (Duration::ZERO, SpinStrategy::SpinLoopHint),
+    /// ];
+    ///
+    /// let sleeper = SpinSleeper::default().with_spin_strategy(SpinStrategy::Staged(STAGES));
+    /// ```
+    Staged(&'static [(Duration, SpinStrategy)]),
+    /// Call the given function each spin iteration.
+    ///
+    /// The function **must not block**, or accuracy will suffer.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep::{SpinSleeper, SpinStrategy};
+    ///
+    /// fn spin_body() {
+    ///     for _ in 0..4 {
+    ///         std::hint::spin_loop();
+    ///     }
+    /// }
+    ///
+    /// let sleeper = SpinSleeper::default().with_spin_strategy(SpinStrategy::Custom(spin_body));
+    /// ```
+    Custom(fn()),
+}
+
+impl SpinStrategy {
+    /// Per-OS default strategy, see [`SpinStrategy::default`].
+    const fn os_default() -> Self {
+        #[cfg(any(windows, all(target_family = "wasm", target_os = "unknown")))]
+        return Self::SpinLoopHint;
+
+        #[cfg(not(any(windows, all(target_family = "wasm", target_os = "unknown"))))]
+        Self::YieldThread
+    }
+
+    /// Returns the approximate CPU cost of spinning with this strategy.
+    ///
+    /// [`SpinStrategy::Staged`] is [`CpuIntensity::Medium`] if its stages mix low & high
+    /// intensity, including the body-less spin used when no stage matches.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep::{CpuIntensity, SpinStrategy};
+    /// # use std::time::Duration;
+    ///
+    /// assert_eq!(SpinStrategy::YieldThread.cpu_intensity(), CpuIntensity::Low);
+    /// assert_eq!(SpinStrategy::SpinLoopHint.cpu_intensity(), CpuIntensity::High);
+    ///
+    /// const STAGES: &[(Duration, SpinStrategy)] = &[
+    ///     (Duration::from_millis(1), SpinStrategy::YieldThread),
+    ///     (Duration::ZERO, SpinStrategy::SpinLoopHint),
+    /// ];
+    /// let staged = SpinStrategy::Staged(STAGES);
+    /// assert_eq!(staged.cpu_intensity(), CpuIntensity::Medium);
+    /// ```
+    pub fn cpu_intensity(self) -> CpuIntensity {
+        match self {
+            Self::YieldThread => CpuIntensity::Low,
+            Self::SpinLoopHint | Self::None | Self::Custom(_) => CpuIntensity::High,
+            Self::Staged(stages) => {
+                // when no stage matches spins without any body
+                let unmatched = match stages.last() {
+                    Some((threshold, _)) if threshold.is_zero() => None,
+                    _ => Some(CpuIntensity::High),
+                };
+                let mut intensities = stages
+                    .iter()
+                    .map(|(_, s)| s.cpu_intensity())
+                    .chain(unmatched);
+                let first = intensities.next().unwrap_or(CpuIntensity::High);
+                match intensities.all(|i| i == first) {
+                    true => first,
+                    false => CpuIntensity::Medium,
+                }
+            }
+        }
+    }
+
+    /// Returns a short human readable description of this strategy & its trade-offs,
+    /// e.g. for presenting strategy choices to end users.
+    pub fn describe(self) -> &'static str {
+        match self {
+            Self::YieldThread => "Yield — lower CPU, slightly less accurate",
+            Self::SpinLoopHint => "Spin — highest accuracy, burns a core",
+            Self::None => "None — tightest spin without CPU hints, burns a core",
+            Self::Custom(_) => "Custom — user supplied spin body",
+            Self::Staged(_) => "Staged — strategy varies with the remaining spin time",
+        }
+    }
+
+    /// Performs a single spin iteration with `remaining` spin time.
+    #[inline]
+    fn spin(self, remaining: Duration) {
+        match self {
+            #[cfg(feature = "std")]
+            Self::YieldThread => thread::yield_now(),
+            #[cfg(not(feature = "std"))]
+            Self::YieldThread => core::hint::spin_loop(),
+            Self::SpinLoopHint => core::hint::spin_loop(),
+            Self::None => {}
+            Self::Custom(f) => f(),
+            Self::Staged(stages) => {
+                if let Some((_, strategy)) = stages.iter().find(|(t, _)| remaining > *t) {
+                    strategy.spin(remaining);
+                }
+            }
+        }
+    }
+}
+
+/// [`SpinStrategy::Custom`] functions are compared by address.
+impl PartialEq for SpinStrategy {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Staged(a), Self::Staged(b)) => a == b,
+            (Self::Custom(a), Self::Custom(b)) => core::ptr::fn_addr_eq(*a, *b),
+            (a, b) => core::mem::discriminant(a) == core::mem::discriminant(b),
+        }
+    }
+}
+
+impl Eq for SpinStrategy {}
+
+impl core::hash::Hash for SpinStrategy {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::mem::discriminant(self).hash(state);
+        match self {
+            Self::Staged(stages) => stages.hash(state),
+            Self::Custom(f) => (*f as *const ()).hash(state),
+            _ => {}
+        }
+    }
+}
+
+/// Approximate CPU cost of a [`SpinStrategy`], see [`SpinStrategy::cpu_intensity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CpuIntensity {
+    /// Spinning frequently gives up the core to other runnable threads.
+    Low,
+    /// Mix of low & high intensity spinning.
+    Medium,
+    /// Spinning fully occupies a core.
+    High,
 }
 
 /// Per-OS default strategy.
 /// * Windows  `SpinLoopHint`
-/// * !Windows `YieldThread`
+/// * wasm32-unknown-unknown `SpinLoopHint`
+/// * Otherwise `YieldThread`
 impl Default for SpinStrategy {
     #[inline]
     fn default() -> Self {
-        #[cfg(windows)]
-        return Self::SpinLoopHint;
-
-        #[cfg(not(windows))]
-        Self::YieldThread
+        Self::os_default()
     }
 }
 
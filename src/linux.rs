@@ -0,0 +1,93 @@
+//! Linux specific functionality.
+use std::{
+    ptr::null_mut,
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Natively sleeps until the `deadline` using `clock_nanosleep` with an absolute
+/// `CLOCK_MONOTONIC` target, the clock backing [`Instant`].
+///
+/// Unlike a relative sleep, time spent before the sleep syscall or waking from
+/// interrupts does not extend the sleep. Returns immediately if the deadline has passed.
+///
+/// Falls back to [`thread::sleep`] if the absolute target cannot be computed.
+pub fn native_sleep_until(deadline: Instant) {
+    // read `Instant` first so the absolute target is never early
+    let Some(remaining) = deadline.checked_duration_since(Instant::now()) else {
+        return;
+    };
+    let Some(target) = monotonic_now().and_then(|now| checked_add(now, remaining)) else {
+        return thread::sleep(remaining);
+    };
+
+    loop {
+        let result = unsafe {
+            libc::clock_nanosleep(
+                libc::CLOCK_MONOTONIC,
+                libc::TIMER_ABSTIME,
+                &target,
+                null_mut(),
+            )
+        };
+        match result {
+            0 => return,
+            libc::EINTR => continue,
+            _ => return thread::sleep(deadline.saturating_duration_since(Instant::now())),
+        }
+    }
+}
+
+/// Returns the current `CLOCK_MONOTONIC` time.
+fn monotonic_now() -> Option<libc::timespec> {
+    let mut now = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    match unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC, &mut now) } {
+        0 => Some(now),
+        _ => None,
+    }
+}
+
+fn checked_add(ts: libc::timespec, duration: Duration) -> Option<libc::timespec> {
+    const NANOS_PER_SEC: libc::c_long = 1_000_000_000;
+
+    let mut tv_sec = ts.tv_sec.checked_add(duration.as_secs().try_into().ok()?)?;
+    // subsec nanos < 1e9 always fits `c_long`
+    let mut tv_nsec = ts.tv_nsec + duration.subsec_nanos() as libc::c_long;
+    if tv_nsec >= NANOS_PER_SEC {
+        tv_nsec -= NANOS_PER_SEC;
+        tv_sec = tv_sec.checked_add(1)?;
+    }
+    Some(libc::timespec { tv_sec, tv_nsec })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sleep_until() {
+        let deadline = Instant::now() + Duration::from_millis(2);
+        native_sleep_until(deadline);
+        assert!(Instant::now() >= deadline);
+    }
+
+    #[test]
+    fn sleep_until_past() {
+        let start = Instant::now();
+        native_sleep_until(start - Duration::from_millis(1));
+        assert!(start.elapsed() < Duration::from_millis(1));
+    }
+
+    #[test]
+    fn timespec_add_carry() {
+        let ts = libc::timespec {
+            tv_sec: 1,
+            tv_nsec: 900_000_000,
+        };
+        let sum = checked_add(ts, Duration::from_millis(1_200)).unwrap();
+        assert_eq!((sum.tv_sec, sum.tv_nsec), (3, 100_000_000));
+    }
+}
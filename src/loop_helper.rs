@@ -1,7 +1,11 @@
 #![allow(deprecated)]
 
 use super::*;
-use std::time::{Duration, Instant};
+use std::{
+    fmt, mem,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 /// Tool for loop rate reporting and control.
 ///
@@ -10,27 +14,89 @@ use std::time::{Duration, Instant};
 ///
 /// Can limit a loop rate to a desired target using
 /// [`LoopHelper::loop_sleep`](struct.LoopHelper.html#method.loop_sleep).
+/// Sleep deadlines are chained, each one target delta after the previous deadline, so work
+/// done outside the `loop_start`/`loop_sleep` window does not lower the effective rate.
+/// When a loop runs long the next deadline is scheduled by the configured
+/// [`MissedTickBehavior`], by default skipping whole missed periods so lag does not accumulate.
+///
+/// Loop deltas & rate reporting use the [`Clock`] `C`, see
+/// [`LoopHelper::with_clock`](struct.LoopHelper.html#method.with_clock).
+/// Sleeping always uses real time.
 #[doc(hidden)]
 #[deprecated = "Use spin_sleep_util crate"]
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct LoopHelper {
+pub struct LoopHelper<C = StdClock> {
     target_delta: Duration,
     report_interval: Duration,
+    min_samples: u32,
     sleeper: SpinSleeper,
+    missed_tick_behavior: MissedTickBehavior,
+    target_rate_fn: Option<TargetRateFn>,
+    clock: C,
 
     last_loop_start: Instant,
-    last_report: Instant,
+    /// Deadline of the next `loop_sleep`, `None` before the first sleep.
+    next_deadline: Option<Instant>,
+    /// `clock` time of the last loop start.
+    last_loop_start_ns: Nanoseconds,
+    /// `clock` time of the last report.
+    last_report: Nanoseconds,
     delta_sum: Duration,
     delta_count: u32,
+    delta_min: Duration,
+    delta_max: Duration,
+    /// Loop delta time not yet consumed by `consume_fixed_step`.
+    fixed_step_accumulator: Duration,
+}
+
+/// Loop timing report returned by
+/// [`LoopHelper::report_detailed`](struct.LoopHelper.html#method.report_detailed).
+#[doc(hidden)]
+#[deprecated = "Use spin_sleep_util crate"]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateReport {
+    /// Mean rate per second.
+    pub mean_rate: RatePerSecond,
+    /// Shortest loop delta.
+    pub min_delta: Duration,
+    /// Longest loop delta, e.g. the worst hitch.
+    pub max_delta: Duration,
+    /// Number of loop deltas recorded.
+    pub sample_count: u32,
+}
+
+/// Target rate closure with the last returned rate.
+#[derive(Clone)]
+struct TargetRateFn {
+    f: Arc<dyn Fn() -> RatePerSecond + Send + Sync>,
+    last_rate: RatePerSecond,
+}
+
+impl fmt::Debug for TargetRateFn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TargetRateFn")
+            .field("last_rate", &self.last_rate)
+            .finish_non_exhaustive()
+    }
 }
 
+impl PartialEq for TargetRateFn {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.f, &other.f) && self.last_rate.to_bits() == other.last_rate.to_bits()
+    }
+}
+
+impl Eq for TargetRateFn {}
+
 /// Builds [`LoopHelper`](struct.LoopHelper.html).
 #[doc(hidden)]
 #[deprecated = "Use spin_sleep_util crate"]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct LoopHelperBuilder {
     report_interval: Option<Duration>,
+    min_samples: Option<u32>,
     sleeper: Option<SpinSleeper>,
+    missed_tick_behavior: Option<MissedTickBehavior>,
 }
 
 impl LoopHelperBuilder {
@@ -48,6 +114,20 @@ impl LoopHelperBuilder {
         self
     }
 
+    /// Sets the minimum number of loop deltas that must be recorded for
+    /// [`LoopHelper::report_rate`](struct.LoopHelper.html#method.report_rate) to return a report.
+    ///
+    /// Reports are returned only once both the report interval has elapsed *and* at least
+    /// `min_samples` deltas have been recorded, so a report may be later than the
+    /// report interval when loops are slow. This avoids erratic reports from few samples,
+    /// e.g. the first report.
+    ///
+    /// Default `1`.
+    pub fn min_samples(mut self, min_samples: u32) -> Self {
+        self.min_samples = Some(min_samples);
+        self
+    }
+
     /// Sets the native sleep accuracy.
     /// See [`SpinSleeper::new`](struct.SpinSleeper.html#method.new) for details.
     ///
@@ -59,6 +139,16 @@ impl LoopHelperBuilder {
         self
     }
 
+    /// Sets the [`MissedTickBehavior`] used to schedule the next
+    /// [`LoopHelper::loop_sleep`](struct.LoopHelper.html#method.loop_sleep) deadline
+    /// when a loop overruns the target delta.
+    ///
+    /// Default [`Skip`](MissedTickBehavior::Skip).
+    pub fn missed_tick_behavior(mut self, behavior: MissedTickBehavior) -> Self {
+        self.missed_tick_behavior = Some(behavior);
+        self
+    }
+
     /// Builds a [`LoopHelper`](struct.LoopHelper.html) without targeting a rate.
     /// This means all calls to
     /// [`LoopHelper::loop_sleep`](struct.LoopHelper.html#method.loop_sleep) will simply return
@@ -71,19 +161,59 @@ impl LoopHelperBuilder {
     /// Note: The `target_rate` only affects
     /// [`LoopHelper::loop_sleep`](struct.LoopHelper.html#method.loop_sleep).
     pub fn build_with_target_rate<R: Into<RatePerSecond>>(self, target_rate: R) -> LoopHelper {
-        let now = Instant::now();
+        self.build_with_target_period(rate_period(target_rate.into()))
+    }
+
+    /// Builds a [`LoopHelper`](struct.LoopHelper.html) targeting the rate returned by
+    /// `target_rate_fn`, which is re-evaluated before each sleep. The loop period is only
+    /// recalculated when the returned rate changes.
+    ///
+    /// Useful for dynamic rates, e.g. matching the current monitor refresh rate which
+    /// may change as a window moves between monitors.
+    /// Note: The target rate only affects
+    /// [`LoopHelper::loop_sleep`](struct.LoopHelper.html#method.loop_sleep).
+    pub fn build_with_target_rate_fn<F>(self, target_rate_fn: F) -> LoopHelper
+    where
+        F: Fn() -> RatePerSecond + Send + Sync + 'static,
+    {
+        let rate = target_rate_fn();
+        let mut helper = self.build_with_target_rate(rate);
+        helper.target_rate_fn = Some(TargetRateFn {
+            f: Arc::new(target_rate_fn),
+            last_rate: rate,
+        });
+        helper
+    }
+
+    /// Builds a [`LoopHelper`](struct.LoopHelper.html) targeting an exact loop period.
+    ///
+    /// Equivalent to [`build_with_target_rate`](#method.build_with_target_rate) with the
+    /// reciprocal rate but avoids `f64` rounding of the period.
+    /// Note: The `target_period` only affects
+    /// [`LoopHelper::loop_sleep`](struct.LoopHelper.html#method.loop_sleep).
+    pub fn build_with_target_period(self, target_period: Duration) -> LoopHelper {
+        let now_ns = StdClock.now();
         let interval = self
             .report_interval
             .unwrap_or_else(|| Duration::from_secs(1));
 
         LoopHelper {
-            target_delta: Duration::from_secs_f64(1.0 / target_rate.into()),
+            target_delta: target_period,
             report_interval: interval,
+            min_samples: self.min_samples.unwrap_or(1).max(1),
             sleeper: self.sleeper.unwrap_or_default(),
-            last_report: now,
-            last_loop_start: now,
+            missed_tick_behavior: self.missed_tick_behavior.unwrap_or_default(),
+            target_rate_fn: None,
+            clock: StdClock,
+            last_report: now_ns,
+            last_loop_start: Instant::now(),
+            next_deadline: None,
+            last_loop_start_ns: now_ns,
             delta_sum: Duration::from_secs(0),
             delta_count: 0,
+            delta_min: Duration::MAX,
+            delta_max: Duration::ZERO,
+            fixed_step_accumulator: Duration::ZERO,
         }
     }
 }
@@ -94,21 +224,97 @@ impl LoopHelper {
     pub fn builder() -> LoopHelperBuilder {
         LoopHelperBuilder {
             report_interval: None,
+            min_samples: None,
             sleeper: None,
+            missed_tick_behavior: None,
+        }
+    }
+}
+
+impl<C: Clock> LoopHelper<C> {
+    /// Returns the helper using the given [`Clock`] for loop deltas & rate reporting,
+    /// e.g. a mock clock for deterministic tests. Rate reporting state is reset.
+    pub fn with_clock<C2: Clock>(self, clock: C2) -> LoopHelper<C2> {
+        let now_ns = clock.now();
+        LoopHelper {
+            target_delta: self.target_delta,
+            report_interval: self.report_interval,
+            min_samples: self.min_samples,
+            sleeper: self.sleeper,
+            missed_tick_behavior: self.missed_tick_behavior,
+            target_rate_fn: self.target_rate_fn,
+            clock,
+            last_loop_start: self.last_loop_start,
+            next_deadline: self.next_deadline,
+            last_loop_start_ns: now_ns,
+            last_report: now_ns,
+            delta_sum: Duration::ZERO,
+            delta_count: 0,
+            delta_min: Duration::MAX,
+            delta_max: Duration::ZERO,
+            fixed_step_accumulator: self.fixed_step_accumulator,
         }
     }
 
     /// Notifies the helper that a new loop has begun.
     /// Returns the delta, the duration since the last call to `loop_start` or `loop_start_s`.
     pub fn loop_start(&mut self) -> Duration {
-        let it_start = Instant::now();
-        let delta = it_start.duration_since(self.last_loop_start);
-        self.last_loop_start = it_start;
+        let it_start_ns = self.clock.now();
+        let delta = Duration::from_nanos(it_start_ns.saturating_sub(self.last_loop_start_ns));
+        self.last_loop_start_ns = it_start_ns;
+        self.last_loop_start = Instant::now();
         self.delta_sum += delta;
         self.delta_count = self.delta_count.wrapping_add(1);
+        self.delta_min = self.delta_min.min(delta);
+        self.delta_max = self.delta_max.max(delta);
+        self.fixed_step_accumulator = self.fixed_step_accumulator.saturating_add(delta);
         delta
     }
 
+    /// Consumes one target delta of the time accumulated by
+    /// [`loop_start`](#method.loop_start) deltas, returning `true` if enough time had
+    /// accumulated. Otherwise returns `false` leaving the remainder for the next loop.
+    ///
+    /// This allows running a simulation at a fixed timestep of the target delta, decoupled
+    /// from the actual loop rate, e.g. the render rate. Returns `false` if there is no
+    /// target rate.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # #![allow(deprecated)]
+    /// # use spin_sleep::LoopHelper;
+    /// # fn simulate(_step: f64) {}
+    /// # fn render(_alpha: f64) {}
+    /// let mut loop_helper = LoopHelper::builder().build_with_target_rate(60.0);
+    /// loop {
+    ///     loop_helper.loop_start();
+    ///     while loop_helper.consume_fixed_step() {
+    ///         simulate(1.0 / 60.0);
+    ///     }
+    ///     render(loop_helper.interpolation_alpha());
+    /// }
+    /// ```
+    pub fn consume_fixed_step(&mut self) -> bool {
+        if self.target_delta.is_zero() || self.fixed_step_accumulator < self.target_delta {
+            return false;
+        }
+        self.fixed_step_accumulator -= self.target_delta;
+        true
+    }
+
+    /// Returns the accumulated time not yet consumed by
+    /// [`consume_fixed_step`](#method.consume_fixed_step) as a fraction of the target delta,
+    /// generally in the range `0.0..1.0`.
+    ///
+    /// Useful to interpolate rendering between the previous & current simulation states.
+    /// Returns `0.0` if there is no target rate.
+    pub fn interpolation_alpha(&self) -> f64 {
+        if self.target_delta.is_zero() {
+            return 0.0;
+        }
+        self.fixed_step_accumulator.as_secs_f64() / self.target_delta.as_secs_f64()
+    }
+
     /// Notifies the helper that a new loop has begun.
     /// Returns the delta, the seconds since the last call to `loop_start` or `loop_start_s`.
     pub fn loop_start_s(&mut self) -> Seconds {
@@ -119,10 +325,30 @@ impl LoopHelper {
     /// [`build_with_target_rate`](struct.LoopHelperBuilder.html#method.build_with_target_rate))
     /// has elapsed. Uses a [`SpinSleeper`](struct.SpinSleeper.html) to sleep the thread to provide
     /// improved accuracy. If the delta has already elapsed this method returns immediately.
+    ///
+    /// The first deadline is one delta after the last `loop_start`, subsequent deadlines are
+    /// one delta after the previous deadline. This avoids time spent outside the
+    /// `loop_start`/`loop_sleep` window accumulating. If a deadline is missed the next is
+    /// scheduled according to the configured [`MissedTickBehavior`].
     pub fn loop_sleep(&mut self) {
-        let elapsed = self.last_loop_start.elapsed();
-        if elapsed < self.target_delta {
-            self.sleeper.sleep(self.target_delta - elapsed);
+        if let Some(deadline) = self.schedule_deadline(self.missed_tick_behavior) {
+            self.sleeper.sleep_until(deadline);
+        }
+    }
+
+    /// Like [`loop_sleep`](struct.LoopHelper.html#method.loop_sleep) but if the deadline
+    /// has already passed the next deadline is scheduled one delta from now,
+    /// i.e. [`MissedTickBehavior::Delay`], regardless of the configured behavior.
+    /// So lost time is never made up by running faster.
+    ///
+    /// Returns `true` if it slept, `false` if behind.
+    pub fn loop_sleep_catch_up(&mut self) -> bool {
+        match self.schedule_deadline(MissedTickBehavior::Delay) {
+            Some(deadline) => {
+                self.sleeper.sleep_until(deadline);
+                true
+            }
+            None => false,
         }
     }
 
@@ -132,30 +358,176 @@ impl LoopHelper {
     /// calls `thread::sleep` and will never spin. This is less accurate than
     /// [`loop_sleep`](struct.LoopHelper.html#method.loop_sleep) but less CPU intensive.
     pub fn loop_sleep_no_spin(&mut self) {
-        let elapsed = self.last_loop_start.elapsed();
-        if elapsed < self.target_delta {
-            native_sleep(self.target_delta - elapsed);
+        if let Some(deadline) = self.schedule_deadline(self.missed_tick_behavior) {
+            native_sleep(deadline.saturating_duration_since(Instant::now()));
         }
     }
 
+    /// Sleeps until the next deadline, notifies the helper that a new loop has begun &
+    /// reports the rate if due. Intended to be called once at the start of each loop.
+    ///
+    /// Equivalent to calling [`loop_sleep`](#method.loop_sleep),
+    /// [`loop_start`](#method.loop_start) & [`report_rate`](#method.report_rate) in that order.
+    /// Deadlines are anchored to the previous deadline so work anywhere in the loop is
+    /// accounted for. This is the recommended replacement for the `loop_start`/`loop_sleep` pair.
+    ///
+    /// Returns the delta, the duration since the previous loop start, and the rate report if due.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # #![allow(deprecated)]
+    /// # use spin_sleep::LoopHelper;
+    /// # fn compute_something(_delta: std::time::Duration) {}
+    /// let mut loop_helper = LoopHelper::builder().build_with_target_rate(144.0);
+    /// loop {
+    ///     let (delta, rate) = loop_helper.frame_step();
+    ///     if let Some(fps) = rate {
+    ///         println!("{fps:.0}fps");
+    ///     }
+    ///     compute_something(delta);
+    /// }
+    /// ```
+    pub fn frame_step(&mut self) -> (Duration, Option<RatePerSecond>) {
+        self.loop_sleep();
+        let delta = self.loop_start();
+        (delta, self.report_rate())
+    }
+
+    /// Returns the current deadline, if not already passed, and schedules the next.
+    fn schedule_deadline(&mut self, missed_tick_behavior: MissedTickBehavior) -> Option<Instant> {
+        if let Some(rate_fn) = &mut self.target_rate_fn {
+            let rate = (rate_fn.f)();
+            if rate.to_bits() != rate_fn.last_rate.to_bits() {
+                rate_fn.last_rate = rate;
+                self.set_target_delta(rate_period(rate));
+            }
+        }
+        if self.target_delta.is_zero() {
+            return None;
+        }
+        let Some(deadline) = self
+            .next_deadline
+            .or_else(|| self.last_loop_start.checked_add(self.target_delta))
+        else {
+            // unrepresentable deadline, effectively never
+            return None;
+        };
+        let now = Instant::now();
+
+        if now > deadline {
+            self.next_deadline =
+                Some(missed_tick_behavior.next_tick(deadline, now, self.target_delta));
+            return None;
+        }
+
+        self.next_deadline = deadline.checked_add(self.target_delta);
+        Some(deadline)
+    }
+
     /// Returns the mean rate per second recorded since the last report. Returns `None` if
     /// the last report was within the configured `report_interval`.
     pub fn report_rate(&mut self) -> Option<RatePerSecond> {
-        let now = Instant::now();
-        if now.duration_since(self.last_report) > self.report_interval && self.delta_count > 0 {
-            let report = Some(f64::from(self.delta_count) / self.delta_sum.as_secs_f64());
+        self.report_detailed().map(|report| report.mean_rate)
+    }
+
+    /// Like [`report_rate`](#method.report_rate) but also returns the min & max loop deltas
+    /// recorded since the last report.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # #![allow(deprecated)]
+    /// # use spin_sleep::LoopHelper;
+    /// # fn compute_something() {}
+    /// let mut loop_helper = LoopHelper::builder()
+    ///     .report_interval_s(1.0)
+    ///     .build_with_target_rate(144.0);
+    /// loop {
+    ///     loop_helper.loop_start();
+    ///     compute_something();
+    ///     if let Some(report) = loop_helper.report_detailed() {
+    ///         println!("{:.0}fps, worst frame {:?}", report.mean_rate, report.max_delta);
+    ///     }
+    ///     loop_helper.loop_sleep();
+    /// }
+    /// ```
+    pub fn report_detailed(&mut self) -> Option<RateReport> {
+        let now = self.clock.now();
+        if Duration::from_nanos(now.saturating_sub(self.last_report)) > self.report_interval
+            && self.delta_count >= self.min_samples
+        {
+            let report = RateReport {
+                mean_rate: f64::from(self.delta_count) / self.delta_sum.as_secs_f64(),
+                min_delta: self.delta_min,
+                max_delta: self.delta_max,
+                sample_count: self.delta_count,
+            };
             self.delta_sum = Duration::from_secs(0);
             self.delta_count = 0;
+            self.delta_min = Duration::MAX;
+            self.delta_max = Duration::ZERO;
             self.last_report = now;
-            report
+            Some(report)
         } else {
             None
         }
     }
 
-    /// Changes the target loop rate
+    /// Changes the target loop rate, taking effect from the next
+    /// [`loop_sleep`](#method.loop_sleep).
+    ///
+    /// Replaces any target rate function set with
+    /// [`build_with_target_rate_fn`](struct.LoopHelperBuilder.html#method.build_with_target_rate_fn).
     pub fn set_target_rate<R: Into<RatePerSecond>>(&mut self, target_rate: R) {
-        self.target_delta = Duration::from_secs_f64(1.0 / target_rate.into());
+        self.target_rate_fn = None;
+        self.set_target_delta(rate_period(target_rate.into()));
+    }
+
+    /// Sets the target loop period, re-anchoring the already scheduled next deadline so the
+    /// change takes effect immediately.
+    fn set_target_delta(&mut self, target_delta: Duration) {
+        if target_delta == self.target_delta {
+            return;
+        }
+        let old = mem::replace(&mut self.target_delta, target_delta);
+        self.next_deadline = match old.is_zero() || target_delta.is_zero() {
+            // no valid chained deadline, re-anchor from the last loop start
+            true => None,
+            false => self
+                .next_deadline
+                .and_then(|deadline| deadline.checked_sub(old))
+                .and_then(|deadline| deadline.checked_add(target_delta)),
+        };
+    }
+
+    /// Returns the configured minimum interval between
+    /// [`report_rate`](#method.report_rate) reports.
+    pub fn report_interval(&self) -> Duration {
+        self.report_interval
+    }
+
+    /// Changes the minimum interval between [`report_rate`](#method.report_rate) reports.
+    ///
+    /// Recorded loop deltas are kept. The next report is due once the new interval has
+    /// elapsed since the last report, so shortening the interval may make it due immediately.
+    pub fn set_report_interval(&mut self, interval: Duration) {
+        self.report_interval = interval;
+    }
+
+    /// Returns the start of the current loop, i.e. the time of the last call to
+    /// [`loop_start`](#method.loop_start), or when built if not yet called.
+    pub fn last_loop_start(&self) -> Instant {
+        self.last_loop_start
+    }
+
+    /// Returns the deadline the next [`loop_sleep`](#method.loop_sleep) will sleep until.
+    ///
+    /// Useful to align other precise sleeps, e.g. on another thread, to the loop pacing.
+    ///
+    /// Returns `None` if the deadline cannot be represented, e.g. for a tiny target rate,
+    /// in which case `loop_sleep` will not sleep.
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.next_deadline
+            .or_else(|| self.last_loop_start.checked_add(self.target_delta))
     }
 
     /// Returns the current target loop rate
@@ -164,12 +536,137 @@ impl LoopHelper {
     }
 }
 
+/// Returns the loop period of a rate.
+///
+/// Non-positive & NaN rates, as well as infinity, have zero period, i.e. no target.
+/// Periods too large to represent saturate to `Duration::MAX`.
+fn rate_period(rate: RatePerSecond) -> Duration {
+    if rate.is_nan() || rate <= 0.0 {
+        return Duration::ZERO;
+    }
+    Duration::try_from_secs_f64(rate.recip()).unwrap_or(Duration::MAX)
+}
+
 #[cfg(test)]
 mod loop_helper_test {
     use super::*;
     use approx::*;
     use std::thread;
 
+    /// Mock clock advanced manually.
+    #[derive(Debug, Default)]
+    struct MockClock(std::cell::Cell<Nanoseconds>);
+
+    impl MockClock {
+        fn advance(&self, duration: Duration) {
+            self.0
+                .set(self.0.get() + duration.as_nanos() as Nanoseconds);
+        }
+    }
+
+    impl Clock for MockClock {
+        fn now(&self) -> Nanoseconds {
+            self.0.get()
+        }
+    }
+
+    #[test]
+    fn rate_reporting_mock_clock() {
+        let clock = MockClock::default();
+        let mut loop_helper = LoopHelper::builder()
+            .report_interval(Duration::from_secs(1))
+            .build_without_target_rate()
+            .with_clock(&clock);
+
+        for _ in 0..50 {
+            clock.advance(Duration::from_millis(10));
+            assert_eq!(loop_helper.loop_start(), Duration::from_millis(10));
+        }
+        // exactly 500ms elapsed, report not yet due
+        assert_eq!(loop_helper.report_rate(), None);
+
+        for _ in 0..51 {
+            clock.advance(Duration::from_millis(10));
+            loop_helper.loop_start();
+        }
+        assert_eq!(loop_helper.report_rate(), Some(100.0));
+        assert_eq!(loop_helper.report_rate(), None);
+    }
+
+    #[test]
+    fn report_detailed() {
+        let clock = MockClock::default();
+        let mut loop_helper = LoopHelper::builder()
+            .report_interval(Duration::from_millis(500))
+            .build_without_target_rate()
+            .with_clock(&clock);
+
+        for ms in [100, 300, 200, 400] {
+            clock.advance(Duration::from_millis(ms));
+            loop_helper.loop_start();
+        }
+        assert_eq!(
+            loop_helper.report_detailed(),
+            Some(RateReport {
+                mean_rate: 4.0,
+                min_delta: Duration::from_millis(100),
+                max_delta: Duration::from_millis(400),
+                sample_count: 4,
+            })
+        );
+
+        // min & max reset after report
+        clock.advance(Duration::from_millis(501));
+        loop_helper.loop_start();
+        let report = loop_helper.report_detailed().unwrap();
+        assert_eq!(report.min_delta, Duration::from_millis(501));
+        assert_eq!(report.max_delta, Duration::from_millis(501));
+        assert_eq!(report.sample_count, 1);
+    }
+
+    #[test]
+    fn set_report_interval() {
+        let clock = MockClock::default();
+        let mut loop_helper = LoopHelper::builder()
+            .report_interval(Duration::from_secs(1))
+            .build_without_target_rate()
+            .with_clock(&clock);
+        assert_eq!(loop_helper.report_interval(), Duration::from_secs(1));
+
+        for _ in 0..30 {
+            clock.advance(Duration::from_millis(10));
+            loop_helper.loop_start();
+        }
+        assert_eq!(loop_helper.report_rate(), None);
+
+        // shortened interval has already elapsed since the last report
+        loop_helper.set_report_interval(Duration::from_millis(250));
+        assert_eq!(loop_helper.report_interval(), Duration::from_millis(250));
+        assert_eq!(loop_helper.report_rate(), Some(100.0));
+    }
+
+    #[test]
+    fn fixed_step() {
+        let clock = MockClock::default();
+        let mut loop_helper = LoopHelper::builder()
+            .build_with_target_period(Duration::from_millis(10))
+            .with_clock(&clock);
+        assert!(!loop_helper.consume_fixed_step());
+
+        clock.advance(Duration::from_millis(25));
+        loop_helper.loop_start();
+        assert!(loop_helper.consume_fixed_step());
+        assert!(loop_helper.consume_fixed_step());
+        assert!(!loop_helper.consume_fixed_step());
+        assert_relative_eq!(loop_helper.interpolation_alpha(), 0.5);
+
+        clock.advance(Duration::from_millis(5));
+        loop_helper.loop_start();
+        assert!(loop_helper.consume_fixed_step());
+        assert!(!loop_helper.consume_fixed_step());
+        assert_relative_eq!(loop_helper.interpolation_alpha(), 0.0);
+    }
+
     #[test]
     fn rate_reporting_using_duration() {
         let mut loop_helper = LoopHelper::builder()
@@ -208,6 +705,23 @@ mod loop_helper_test {
         assert_relative_eq!(reported_rate, expected_rate, epsilon = 1e-9);
     }
 
+    #[test]
+    fn rate_reporting_min_samples() {
+        let mut loop_helper = LoopHelper::builder()
+            .report_interval_s(0.0)
+            .min_samples(5)
+            .build_without_target_rate();
+
+        for _ in 0..4 {
+            loop_helper.loop_start();
+            thread::sleep(Duration::new(0, 1000));
+        }
+        assert_eq!(loop_helper.report_rate(), None);
+
+        loop_helper.loop_start();
+        assert!(loop_helper.report_rate().is_some());
+    }
+
     #[test]
     fn loop_sleep_already_past_target() {
         let mut loop_helper = LoopHelper::builder()
@@ -219,6 +733,89 @@ mod loop_helper_test {
         loop_helper.loop_sleep(); // should not panic
     }
 
+    #[test]
+    fn loop_sleep_deadlines_do_not_accumulate() {
+        let mut loop_helper = LoopHelper::builder().build_with_target_rate(100.0);
+        loop_helper.loop_start();
+        let start = loop_helper.last_loop_start;
+        for _ in 0..5 {
+            loop_helper.loop_sleep();
+            loop_helper.loop_start();
+            // work outside the loop_start/loop_sleep window
+            thread::sleep(Duration::from_micros(100));
+        }
+        assert_eq!(
+            loop_helper.next_deadline,
+            Some(start + loop_helper.target_delta * 6)
+        );
+    }
+
+    #[test]
+    fn frame_step() {
+        let mut loop_helper = LoopHelper::builder().build_with_target_rate(100.0);
+        let start = loop_helper.last_loop_start;
+        let mut delta_sum = Duration::ZERO;
+        for _ in 0..3 {
+            let (delta, _) = loop_helper.frame_step();
+            delta_sum += delta;
+            // work
+            thread::sleep(Duration::from_micros(100));
+        }
+        assert!(delta_sum >= loop_helper.target_delta * 3);
+        assert_eq!(
+            loop_helper.next_deadline,
+            Some(start + loop_helper.target_delta * 4)
+        );
+    }
+
+    #[test]
+    fn instant_accessors() {
+        let mut loop_helper = LoopHelper::builder().build_with_target_rate(100.0);
+        loop_helper.loop_start();
+        let start = loop_helper.last_loop_start();
+        assert_eq!(
+            loop_helper.next_deadline(),
+            Some(start + loop_helper.target_delta)
+        );
+
+        loop_helper.loop_sleep();
+        assert_eq!(
+            loop_helper.next_deadline(),
+            Some(start + loop_helper.target_delta * 2)
+        );
+    }
+
+    #[test]
+    fn loop_sleep_missed_skip() {
+        let mut loop_helper = LoopHelper::builder()
+            .missed_tick_behavior(MissedTickBehavior::Skip)
+            .build_with_target_rate(100.0);
+        let start = loop_helper.last_loop_start;
+
+        thread::sleep(Duration::from_millis(25));
+        loop_helper.loop_sleep(); // missed, should not sleep
+        assert_eq!(
+            loop_helper.next_deadline,
+            Some(start + loop_helper.target_delta * 3)
+        );
+    }
+
+    #[test]
+    fn loop_sleep_catch_up() {
+        let mut loop_helper = LoopHelper::builder()
+            .missed_tick_behavior(MissedTickBehavior::Burst)
+            .build_with_target_rate(100.0);
+
+        thread::sleep(Duration::from_millis(25));
+        assert!(!loop_helper.loop_sleep_catch_up());
+        let next_deadline = loop_helper.next_deadline.unwrap();
+        let from_now = next_deadline.duration_since(Instant::now());
+        assert!(from_now > Duration::from_millis(5), "{from_now:?}");
+
+        assert!(loop_helper.loop_sleep_catch_up());
+        assert!(Instant::now() >= next_deadline);
+    }
+
     #[test]
     fn get_set_target_rate() {
         let mut loop_helper = LoopHelper::builder().build_with_target_rate(100.0);
@@ -227,4 +824,74 @@ mod loop_helper_test {
         loop_helper.set_target_rate(150.0);
         assert_relative_eq!(loop_helper.target_rate(), 150.0, epsilon = 1e-4);
     }
+
+    #[test]
+    fn build_with_target_rate_fn() {
+        use std::sync::atomic::{AtomicU64, Ordering};
+
+        let rate = Arc::new(AtomicU64::new(100.0_f64.to_bits()));
+        let mut loop_helper = LoopHelper::builder().build_with_target_rate_fn({
+            let rate = Arc::clone(&rate);
+            move || f64::from_bits(rate.load(Ordering::Relaxed))
+        });
+        assert_eq!(loop_helper.target_delta, Duration::from_millis(10));
+
+        rate.store(1000.0_f64.to_bits(), Ordering::Relaxed);
+        loop_helper.loop_sleep();
+        assert_eq!(loop_helper.target_delta, Duration::from_millis(1));
+
+        loop_helper.set_target_rate(200.0);
+        loop_helper.loop_sleep();
+        assert_eq!(loop_helper.target_delta, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn set_target_rate_reanchors_deadline() {
+        let mut loop_helper = LoopHelper::builder().build_with_target_rate(1.0);
+        let start = loop_helper.last_loop_start();
+        // as if a 1s loop_sleep just slept until `start` & chained the next deadline
+        loop_helper.next_deadline = Some(start + Duration::from_secs(1));
+
+        loop_helper.set_target_rate(1000.0);
+        assert_eq!(
+            loop_helper.next_deadline(),
+            Some(start + Duration::from_millis(1))
+        );
+
+        let before = Instant::now();
+        loop_helper.loop_sleep();
+        assert!(before.elapsed() < Duration::from_millis(500));
+    }
+
+    #[test]
+    fn low_target_rates() {
+        let loop_helper = LoopHelper::builder().build_with_target_rate(0.4);
+        assert_eq!(loop_helper.target_delta, Duration::from_millis(2500));
+
+        let loop_helper = LoopHelper::builder().build_with_target_rate(0.001);
+        assert_eq!(loop_helper.target_delta, Duration::from_secs(1000));
+
+        let mut loop_helper = LoopHelper::builder().build_with_target_rate(1e-30);
+        assert_eq!(loop_helper.target_delta, Duration::MAX);
+        assert_eq!(loop_helper.next_deadline(), None);
+        loop_helper.loop_sleep(); // unrepresentable deadline, should not sleep or panic
+    }
+
+    #[test]
+    fn no_target_rate() {
+        for rate in [f64::INFINITY, 0.0, -1.0, f64::NAN] {
+            let mut loop_helper = LoopHelper::builder().build_with_target_rate(rate);
+            assert_eq!(loop_helper.target_delta, Duration::ZERO);
+            loop_helper.loop_sleep(); // should not sleep
+        }
+        let loop_helper = LoopHelper::builder().build_without_target_rate();
+        assert_eq!(loop_helper.target_delta, Duration::ZERO);
+    }
+
+    #[test]
+    fn build_with_target_period_exact() {
+        let period = Duration::from_secs(1) / 240;
+        let loop_helper = LoopHelper::builder().build_with_target_period(period);
+        assert_eq!(loop_helper.target_delta, period);
+    }
 }
@@ -0,0 +1,101 @@
+//! macOS specific functionality.
+use std::{io, sync::OnceLock, time::Duration};
+
+/// Promotes the current thread to the realtime `THREAD_TIME_CONSTRAINT_POLICY`, reducing
+/// native sleep wake up jitter.
+///
+/// * `period`: Nominal interval between the start of each loop, e.g. the loop target delta.
+///   Zero indicates no inherent periodicity.
+/// * `computation`: CPU time needed per period, e.g. the expected per loop work.
+/// * `constraint`: Max real time from the start of a period to completion of the
+///   computation, at most `period`.
+///
+/// For a ~1ms loop sensible values are a `period` & `constraint` of 1ms with a
+/// `computation` of ~250µs. Over-stating `computation` can cause the scheduler to demote
+/// the thread, so measure the actual work.
+///
+/// Should be called on the thread that will run the loop, e.g. an audio/render thread,
+/// before entering it. Sandboxed or hardened apps may need appropriate entitlements,
+/// otherwise an error is returned.
+///
+/// # Errors
+/// Errors if `computation > constraint`, or `constraint > period` for a non-zero `period`,
+/// or if the policy is rejected by the kernel.
+///
+/// # Example
+/// ```no_run
+/// # use std::time::Duration;
+/// spin_sleep::mac::set_realtime_priority(
+///     Duration::from_millis(1),
+///     Duration::from_micros(250),
+///     Duration::from_millis(1),
+/// )
+/// .expect("realtime priority");
+/// ```
+pub fn set_realtime_priority(
+    period: Duration,
+    computation: Duration,
+    constraint: Duration,
+) -> io::Result<()> {
+    if computation > constraint || (!period.is_zero() && constraint > period) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "expected computation <= constraint <= period",
+        ));
+    }
+
+    let mut policy = libc::thread_time_constraint_policy {
+        period: to_abs_time(period)?,
+        computation: to_abs_time(computation)?,
+        constraint: to_abs_time(constraint)?,
+        preemptible: 1,
+    };
+    let result = unsafe {
+        libc::thread_policy_set(
+            libc::pthread_mach_thread_np(libc::pthread_self()),
+            libc::THREAD_TIME_CONSTRAINT_POLICY as _,
+            &mut policy as *mut _ as libc::thread_policy_t,
+            libc::THREAD_TIME_CONSTRAINT_POLICY_COUNT,
+        )
+    };
+    match result {
+        libc::KERN_SUCCESS => Ok(()),
+        code => Err(io::Error::other(format!(
+            "thread_policy_set failed: kern_return_t {code}"
+        ))),
+    }
+}
+
+/// Converts the `duration` into mach absolute time units.
+fn to_abs_time(duration: Duration) -> io::Result<u32> {
+    let timebase = timebase().ok_or_else(|| io::Error::other("mach_timebase_info failed"))?;
+    let ticks = duration.as_nanos() * u128::from(timebase.denom) / u128::from(timebase.numer);
+    ticks
+        .try_into()
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "duration too long"))
+}
+
+/// `mach_timebase_info_data_t`, declared here as the _libc_ version is deprecated.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct MachTimebaseInfo {
+    numer: u32,
+    denom: u32,
+}
+
+extern "C" {
+    fn mach_timebase_info(info: *mut MachTimebaseInfo) -> libc::kern_return_t;
+}
+
+/// Returns the cached mach absolute time to nanoseconds ratio.
+fn timebase() -> Option<MachTimebaseInfo> {
+    static TIMEBASE: OnceLock<Option<MachTimebaseInfo>> = OnceLock::new();
+
+    *TIMEBASE.get_or_init(|| {
+        let mut info = MachTimebaseInfo { numer: 0, denom: 0 };
+        match unsafe { mach_timebase_info(&mut info) } {
+            libc::KERN_SUCCESS if info.numer != 0 => Some(info),
+            _ => None,
+        }
+    })
+}
@@ -0,0 +1,93 @@
+use super::*;
+use std::{collections::HashMap, sync::Mutex};
+
+/// Metric names for a prefix, built once & shared by all sleepers using the prefix.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(crate) struct MetricNames {
+    prefix: &'static str,
+    overshoot_ns: &'static str,
+    sleeps: &'static str,
+}
+
+/// Default metric names, see [`SpinSleeper::with_metrics_prefix`].
+pub(crate) static DEFAULT_NAMES: MetricNames = MetricNames {
+    prefix: "spin_sleep",
+    overshoot_ns: "spin_sleep.overshoot_ns",
+    sleeps: "spin_sleep.sleeps",
+};
+
+impl MetricNames {
+    /// Returns the names for `prefix`, leaking them on first use of each distinct prefix.
+    fn interned(prefix: &'static str) -> &'static Self {
+        static INTERNED: OnceLock<Mutex<HashMap<&'static str, &'static MetricNames>>> =
+            OnceLock::new();
+
+        if prefix == DEFAULT_NAMES.prefix {
+            return &DEFAULT_NAMES;
+        }
+        let mut interned = INTERNED
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        interned.entry(prefix).or_insert_with(|| {
+            Box::leak(Box::new(Self {
+                prefix,
+                overshoot_ns: format!("{prefix}.overshoot_ns").leak(),
+                sleeps: format!("{prefix}.sleeps").leak(),
+            }))
+        })
+    }
+}
+
+impl SpinSleeper {
+    /// Returns the configured metric name prefix, see [`SpinSleeper::with_metrics_prefix`].
+    pub fn metrics_prefix(self) -> &'static str {
+        self.metric_names.prefix
+    }
+
+    /// Returns a spin sleeper recording metrics named with the given `prefix`,
+    /// i.e. `{prefix}.overshoot_ns` & `{prefix}.sleeps`. Default `"spin_sleep"`.
+    ///
+    /// Names are built once per distinct prefix, so recording does not allocate.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep::SpinSleeper;
+    ///
+    /// let sleeper = SpinSleeper::default().with_metrics_prefix("render.frame_sleep");
+    /// assert_eq!(sleeper.metrics_prefix(), "render.frame_sleep");
+    /// ```
+    pub fn with_metrics_prefix(mut self, prefix: &'static str) -> Self {
+        self.metric_names = MetricNames::interned(prefix);
+        self
+    }
+
+    /// Records a sleep `overshoot` into the overshoot histogram & increments the sleep counter.
+    #[inline]
+    pub(crate) fn record_sleep_metrics(self, overshoot: Duration) {
+        let names = self.metric_names;
+        metrics::histogram!(names.overshoot_ns).record(overshoot.as_nanos() as f64);
+        metrics::counter!(names.sleeps).increment(1);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn interned_names() {
+        let sleeper = SpinSleeper::default().with_metrics_prefix("render.frame_sleep");
+        assert_eq!(
+            sleeper.metric_names.overshoot_ns,
+            "render.frame_sleep.overshoot_ns"
+        );
+        assert_eq!(sleeper.metric_names.sleeps, "render.frame_sleep.sleeps");
+
+        let again = SpinSleeper::default().with_metrics_prefix("render.frame_sleep");
+        assert!(std::ptr::eq(sleeper.metric_names, again.metric_names));
+
+        let default = SpinSleeper::default().with_metrics_prefix("spin_sleep");
+        assert!(std::ptr::eq(default.metric_names, &DEFAULT_NAMES));
+    }
+}
@@ -0,0 +1,193 @@
+use std::time::{Duration, Instant};
+
+/// Defines the behavior of an interval when it misses a tick.
+///
+/// Used by `spin_sleep_util::Interval` & `LoopHelper`.
+///
+/// Generally, a tick is missed if too much time is spent without calling
+/// `Interval::tick()`.
+///
+/// Default [`Skip`](MissedTickBehavior::Skip).
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissedTickBehavior {
+    /// Ticks as fast as possible until caught up.
+    ///
+    /// When this strategy is used, `Interval` schedules ticks "normally" (the
+    /// same as it would have if the ticks hadn't been delayed), which results
+    /// in it firing ticks as fast as possible until it is caught up in time to
+    /// where it should be. Unlike [`Delay`] and [`Skip`], the ticks yielded
+    /// when `Burst` is used (the [`Instant`]s that `tick`
+    /// yields) aren't different than they would have been if a tick had not
+    /// been missed. Like [`Skip`], and unlike [`Delay`], the ticks may be
+    /// shortened.
+    ///
+    /// This looks something like this:
+    /// ```text
+    /// Expected ticks: |     1     |     2     |     3     |     4     |     5     |     6     |
+    /// Actual ticks:   | work -----|          delay          | work | work | work -| work -----|
+    /// ```
+    ///
+    /// In code:
+    ///
+    /// ```no_run
+    /// use spin_sleep::MissedTickBehavior;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let period = Duration::from_millis(50);
+    /// let start = Instant::now();
+    ///
+    /// // The tick 50ms after `start` was missed by a task that took 200ms
+    /// let missed_tick = start + period;
+    /// let now = start + Duration::from_millis(200);
+    ///
+    /// // The next tick is one period after the missed tick, so is also already due.
+    /// // Ticks resolve immediately until caught up at 200ms, then every 50ms again.
+    /// let next_tick = MissedTickBehavior::Burst.next_tick(missed_tick, now, period);
+    /// assert_eq!(next_tick, start + Duration::from_millis(100));
+    /// ```
+    ///
+    /// [`Delay`]: MissedTickBehavior::Delay
+    /// [`Skip`]: MissedTickBehavior::Skip
+    Burst,
+
+    /// Tick at multiples of `period` from when `tick` was called, rather than
+    /// from `start`.
+    ///
+    /// When this strategy is used and `Interval` has missed a tick, instead
+    /// of scheduling ticks to fire at multiples of `period` from `start` (the
+    /// time when the first tick was fired), it schedules all future ticks to
+    /// happen at a regular `period` from the point when `tick` was called.
+    /// Unlike [`Burst`] and [`Skip`], ticks are not shortened, and they aren't
+    /// guaranteed to happen at a multiple of `period` from `start` any longer.
+    ///
+    /// This looks something like this:
+    /// ```text
+    /// Expected ticks: |     1     |     2     |     3     |     4     |     5     |     6     |
+    /// Actual ticks:   | work -----|          delay          | work -----| work -----| work -----|
+    /// ```
+    ///
+    /// In code:
+    ///
+    /// ```no_run
+    /// use spin_sleep::MissedTickBehavior;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let period = Duration::from_millis(50);
+    /// let start = Instant::now();
+    ///
+    /// // The tick 50ms after `start` was missed by a task that took more than 50ms
+    /// let missed_tick = start + period;
+    /// let now = start + Duration::from_millis(110);
+    ///
+    /// // Rather than resolving immediately, as might happen with the `Burst` or `Skip`
+    /// // behaviors, the next tick is scheduled 50ms (or whatever the `period` is) from
+    /// // when we recognized the missed tick, not from when we were *supposed* to tick
+    /// let next_tick = MissedTickBehavior::Delay.next_tick(missed_tick, now, period);
+    /// assert_eq!(next_tick, now + period);
+    /// ```
+    ///
+    /// [`Burst`]: MissedTickBehavior::Burst
+    /// [`Skip`]: MissedTickBehavior::Skip
+    Delay,
+
+    /// Skips missed ticks and tick on the next multiple of `period` from
+    /// `start`.
+    ///
+    /// When this strategy is used, `Interval` schedules the next tick to fire
+    /// at the next-closest tick that is a multiple of `period` away from
+    /// `start` (the point where `Interval` first ticked). Like [`Burst`], all
+    /// ticks remain multiples of `period` away from `start`, but unlike
+    /// [`Burst`], the ticks may not be *one* multiple of `period` away from the
+    /// last tick. Like [`Delay`], the ticks are no longer the same as they
+    /// would have been if ticks had not been missed, but unlike [`Delay`], and
+    /// like [`Burst`], the ticks may be shortened to be less than one `period`
+    /// away from each other.
+    ///
+    /// This looks something like this:
+    /// ```text
+    /// Expected ticks: |     1     |     2     |     3     |     4     |     5     |     6     |
+    /// Actual ticks:   | work -----|          delay          | work ---| work -----| work -----|
+    /// ```
+    ///
+    /// In code:
+    ///
+    /// ```no_run
+    /// use spin_sleep::MissedTickBehavior;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let period = Duration::from_millis(50);
+    /// let start = Instant::now();
+    ///
+    /// // The tick 50ms after `start` was missed by a task that took 75ms
+    /// let missed_tick = start + period;
+    /// let now = missed_tick + Duration::from_millis(75);
+    ///
+    /// // The next tick is 25ms later, 150ms after `start`, which is the closest
+    /// // multiple of `period` from `start` after the current time
+    /// let next_tick = MissedTickBehavior::Skip.next_tick(missed_tick, now, period);
+    /// assert_eq!(next_tick, start + Duration::from_millis(150));
+    /// ```
+    ///
+    /// [`Burst`]: MissedTickBehavior::Burst
+    /// [`Delay`]: MissedTickBehavior::Delay
+    #[default]
+    Skip,
+}
+
+impl MissedTickBehavior {
+    /// Returns when the next tick should happen after a `missed_tick` at `now`.
+    ///
+    /// # Panics
+    /// `Skip` panics if `period` is zero.
+    pub fn next_tick(self, missed_tick: Instant, now: Instant, period: Duration) -> Instant {
+        match self {
+            Self::Burst => missed_tick + period,
+            Self::Delay => now + period,
+            Self::Skip => {
+                now + period
+                    - Duration::from_nanos(
+                        ((now - missed_tick).as_nanos() % period.as_nanos())
+                            .try_into()
+                            // This operation is practically guaranteed not to
+                            // fail, as in order for it to fail, `period` would
+                            // have to be longer than `now - timeout`, and both
+                            // would have to be longer than 584 years.
+                            //
+                            // If it did fail, there's not a good way to pass
+                            // the error along to the user, so we just panic.
+                            .expect(
+                                "too much time has elapsed since the interval was supposed to tick",
+                            ),
+                    )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    /// Property: ticks remain on the period grid & `Delay` never shortens a period.
+    #[test]
+    fn prop_next_tick() {
+        let mut rng = StdRng::seed_from_u64(458);
+        let base = Instant::now();
+        for _ in 0..10_000 {
+            let period = Duration::from_nanos(rng.gen_range(1..1_000_000_000));
+            let missed_tick = base + Duration::from_nanos(rng.gen_range(0..1_000_000_000));
+            let now = missed_tick + period.mul_f64(rng.gen_range(0.0..10.0));
+
+            let skip = MissedTickBehavior::Skip.next_tick(missed_tick, now, period);
+            assert!(skip > now && skip <= now + period);
+            assert_eq!((skip - missed_tick).as_nanos() % period.as_nanos(), 0);
+
+            let delay = MissedTickBehavior::Delay.next_tick(missed_tick, now, period);
+            assert_eq!(delay - now, period);
+
+            let burst = MissedTickBehavior::Burst.next_tick(missed_tick, now, period);
+            assert_eq!(burst, missed_tick + period);
+        }
+    }
+}
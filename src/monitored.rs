@@ -0,0 +1,162 @@
+use super::*;
+use std::sync::atomic::{AtomicU64, Ordering::Relaxed};
+
+/// Number of log2 histogram buckets, one for zero & one per `u64` bit.
+const BUCKETS: usize = 65;
+
+/// [`SpinSleeper`] that records the overshoot of every sleep into a compact log2 histogram.
+///
+/// Recording is lock-free so the sleeper may be shared between threads, e.g. in an `Arc`,
+/// with [`MonitoredSpinSleeper::stats`] polled for monitoring.
+///
+/// # Example
+/// ```
+/// use spin_sleep::{MonitoredSpinSleeper, SpinSleeper};
+/// # use std::time::Duration;
+///
+/// let sleeper = MonitoredSpinSleeper::new(SpinSleeper::default());
+/// for _ in 0..10 {
+///     sleeper.sleep(Duration::from_millis(1));
+/// }
+/// let stats = sleeper.stats();
+/// assert_eq!(stats.count, 10);
+/// println!("overshoot mean {:?}, p99 {:?}", stats.mean, stats.p99);
+/// ```
+#[derive(Debug)]
+pub struct MonitoredSpinSleeper {
+    sleeper: SpinSleeper,
+    /// Overshoot counts, bucket `i > 0` covers `2^(i-1)..2^i` nanoseconds.
+    buckets: [AtomicU64; BUCKETS],
+    count: AtomicU64,
+    sum_ns: AtomicU64,
+    max_ns: AtomicU64,
+}
+
+/// Overshoot statistics returned by [`MonitoredSpinSleeper::stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct OvershootStats {
+    /// Mean overshoot.
+    pub mean: Duration,
+    /// Approximate 99th percentile overshoot, the upper bound of the log2 histogram
+    /// bucket containing it, capped by `max`.
+    pub p99: Duration,
+    /// Max overshoot.
+    pub max: Duration,
+    /// Number of recorded sleeps.
+    pub count: u64,
+}
+
+impl MonitoredSpinSleeper {
+    /// Constructs a new monitored sleeper with no recorded sleeps.
+    pub fn new(sleeper: SpinSleeper) -> Self {
+        Self {
+            sleeper,
+            buckets: [const { AtomicU64::new(0) }; BUCKETS],
+            count: AtomicU64::new(0),
+            sum_ns: AtomicU64::new(0),
+            max_ns: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the inner [`SpinSleeper`].
+    pub fn spin_sleeper(&self) -> SpinSleeper {
+        self.sleeper
+    }
+
+    /// Sleeps using the inner [`SpinSleeper::sleep`] recording the overshoot.
+    ///
+    /// Returns the actual elapsed duration, see [`SpinSleeper::sleep`].
+    pub fn sleep(&self, duration: Duration) -> Duration {
+        let elapsed = self.sleeper.sleep(duration);
+        self.record(elapsed.saturating_sub(duration));
+        elapsed
+    }
+
+    /// Sleeps using the inner [`SpinSleeper::sleep_until`] recording the overshoot.
+    pub fn sleep_until(&self, deadline: Instant) {
+        self.sleeper.sleep_until(deadline);
+        self.record(Instant::now().saturating_duration_since(deadline));
+    }
+
+    /// Returns statistics of all recorded sleep overshoots since construction or
+    /// [reset](Self::reset).
+    pub fn stats(&self) -> OvershootStats {
+        let count = self.count.load(Relaxed);
+        if count == 0 {
+            return OvershootStats::default();
+        }
+        let max_ns = self.max_ns.load(Relaxed);
+
+        let p99_rank = count - count / 100;
+        let mut seen = 0;
+        let mut p99_ns = max_ns;
+        for (idx, bucket) in self.buckets.iter().enumerate() {
+            seen += bucket.load(Relaxed);
+            if seen >= p99_rank {
+                p99_ns = bucket_upper_bound(idx).min(max_ns);
+                break;
+            }
+        }
+
+        OvershootStats {
+            mean: Duration::from_nanos(self.sum_ns.load(Relaxed) / count),
+            p99: Duration::from_nanos(p99_ns),
+            max: Duration::from_nanos(max_ns),
+            count,
+        }
+    }
+
+    /// Clears all recorded sleeps.
+    pub fn reset(&self) {
+        for bucket in &self.buckets {
+            bucket.store(0, Relaxed);
+        }
+        self.count.store(0, Relaxed);
+        self.sum_ns.store(0, Relaxed);
+        self.max_ns.store(0, Relaxed);
+    }
+
+    fn record(&self, overshoot: Duration) {
+        let ns = u64::try_from(overshoot.as_nanos()).unwrap_or(u64::MAX);
+        let idx = (u64::BITS - ns.leading_zeros()) as usize;
+        self.buckets[idx].fetch_add(1, Relaxed);
+        self.count.fetch_add(1, Relaxed);
+        self.sum_ns.fetch_add(ns, Relaxed);
+        self.max_ns.fetch_max(ns, Relaxed);
+    }
+}
+
+/// Returns the max nanoseconds of histogram bucket `idx`.
+fn bucket_upper_bound(idx: usize) -> u64 {
+    match idx {
+        0 => 0,
+        64.. => u64::MAX,
+        _ => (1 << idx) - 1,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let sleeper = MonitoredSpinSleeper::new(SpinSleeper::default());
+        assert_eq!(sleeper.stats(), OvershootStats::default());
+
+        for _ in 0..99 {
+            sleeper.record(Duration::from_nanos(1_000));
+        }
+        sleeper.record(Duration::from_nanos(100_000));
+
+        let stats = sleeper.stats();
+        assert_eq!(stats.count, 100);
+        assert_eq!(stats.mean, Duration::from_nanos(1_990));
+        assert_eq!(stats.max, Duration::from_nanos(100_000));
+        // 1000ns is in the 512..1024 bucket
+        assert_eq!(stats.p99, Duration::from_nanos(1_023));
+
+        sleeper.reset();
+        assert_eq!(sleeper.stats(), OvershootStats::default());
+    }
+}
@@ -0,0 +1,81 @@
+use super::*;
+
+/// Default interval between [`with_deadline`] polls.
+pub(crate) const POLL_INTERVAL: Duration = Duration::from_micros(10);
+
+/// Repeatedly calls the non-blocking `op` with short precise spin-sleeps in between until it
+/// returns `Some` or the `deadline` passes, returning `None`.
+///
+/// `op` is always called at least once, and once more at the deadline.
+/// Polls every 10µs, see [`with_deadline_poll`] to configure the poll interval.
+///
+/// This achieves tight timeout accuracy but is a busy-wait, consuming a CPU core for the
+/// entire wait.
+///
+/// # Example
+/// ```
+/// use std::{
+///     sync::atomic::{AtomicBool, Ordering},
+///     time::{Duration, Instant},
+/// };
+///
+/// let ready = AtomicBool::new(false);
+/// let deadline = Instant::now() + Duration::from_micros(100);
+///
+/// let result = spin_sleep::with_deadline(deadline, || ready.load(Ordering::Acquire).then_some(()));
+/// assert_eq!(result, None);
+/// ```
+pub fn with_deadline<T>(deadline: Instant, op: impl FnMut() -> Option<T>) -> Option<T> {
+    with_deadline_poll(deadline, POLL_INTERVAL, op)
+}
+
+/// Like [`with_deadline`] but with a configurable `poll_interval` between calls to `op`.
+pub fn with_deadline_poll<T>(
+    deadline: Instant,
+    poll_interval: Duration,
+    mut op: impl FnMut() -> Option<T>,
+) -> Option<T> {
+    let sleeper = SpinSleeper::default();
+    loop {
+        if let Some(value) = op() {
+            return Some(value);
+        }
+
+        let now = Instant::now();
+        if now >= deadline {
+            return None;
+        }
+        let next_poll = now.checked_add(poll_interval).unwrap_or(deadline);
+        sleeper.sleep_until(next_poll.min(deadline));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn timeout() {
+        let before = Instant::now();
+        let deadline = before + Duration::from_millis(2);
+        let mut calls = 0;
+        let result: Option<()> = with_deadline_poll(deadline, Duration::from_micros(500), || {
+            calls += 1;
+            None
+        });
+        assert_eq!(result, None);
+        assert!(Instant::now() >= deadline);
+        assert!((2..=6).contains(&calls), "{calls}");
+    }
+
+    #[test]
+    fn some() {
+        let deadline = Instant::now() + Duration::from_secs(1);
+        let mut calls = 0;
+        let result = with_deadline(deadline, || {
+            calls += 1;
+            (calls == 3).then_some(calls)
+        });
+        assert_eq!(result, Some(3));
+    }
+}
@@ -0,0 +1,137 @@
+use super::*;
+use std::fmt;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Units that may be converted into a sleep [`Duration`], see [`SpinSleeper::sleep_for`].
+///
+/// Implemented for:
+/// * [`Duration`].
+/// * [`Seconds`] `f64`, non-positive & `NaN` values are zero. Infinite or too large values
+///   saturate to `Duration::MAX`.
+/// * [`Nanoseconds`] `u64`.
+/// * `time::Duration` with the **time** feature, negative values are zero.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait IntoSleepDuration: sealed::Sealed {
+    /// Converts into a sleep duration.
+    fn into_sleep_duration(self) -> Duration;
+}
+
+impl sealed::Sealed for Duration {}
+impl IntoSleepDuration for Duration {
+    #[inline]
+    fn into_sleep_duration(self) -> Duration {
+        self
+    }
+}
+
+impl sealed::Sealed for Seconds {}
+impl IntoSleepDuration for Seconds {
+    #[inline]
+    fn into_sleep_duration(self) -> Duration {
+        match self > 0.0 {
+            true => Duration::try_from_secs_f64(self).unwrap_or(Duration::MAX),
+            false => Duration::ZERO,
+        }
+    }
+}
+
+impl sealed::Sealed for Nanoseconds {}
+impl IntoSleepDuration for Nanoseconds {
+    #[inline]
+    fn into_sleep_duration(self) -> Duration {
+        Duration::from_nanos(self)
+    }
+}
+
+#[cfg(feature = "time")]
+impl sealed::Sealed for time::Duration {}
+#[cfg(feature = "time")]
+impl IntoSleepDuration for time::Duration {
+    #[inline]
+    fn into_sleep_duration(self) -> Duration {
+        self.try_into().unwrap_or(Duration::ZERO)
+    }
+}
+
+/// Error converting a time value into a sleep, see [`SpinSleeper::try_sleep_ns`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TimeError {
+    /// The sleep deadline is not representable.
+    Overflow,
+}
+
+impl fmt::Display for TimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow => f.write_str("sleep deadline overflow"),
+        }
+    }
+}
+
+impl std::error::Error for TimeError {}
+
+impl SpinSleeper {
+    /// Puts the [current thread to sleep](fn.native_sleep.html) for the given duration
+    /// less the configured native accuracy. Then spins until the specified duration has elapsed.
+    ///
+    /// The duration may be any [`IntoSleepDuration`] unit.
+    ///
+    /// Returns the actual elapsed duration.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// let sleeper = spin_sleep::SpinSleeper::default();
+    ///
+    /// sleeper.sleep_for(Duration::from_millis(10));
+    /// sleeper.sleep_for(0.01); // seconds
+    /// sleeper.sleep_for(10_000_000_u64); // nanoseconds
+    /// ```
+    pub fn sleep_for<T: IntoSleepDuration>(self, duration: T) -> Duration {
+        self.sleep(duration.into_sleep_duration())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn seconds() {
+        assert_eq!(0.25.into_sleep_duration(), Duration::from_millis(250));
+        assert_eq!((-1.0).into_sleep_duration(), Duration::ZERO);
+        assert_eq!(f64::NAN.into_sleep_duration(), Duration::ZERO);
+        assert_eq!(f64::INFINITY.into_sleep_duration(), Duration::MAX);
+        assert_eq!(1e300.into_sleep_duration(), Duration::MAX);
+    }
+
+    #[test]
+    fn nanoseconds() {
+        assert_eq!(1_500_u64.into_sleep_duration(), Duration::from_nanos(1500));
+    }
+
+    #[test]
+    fn try_sleep_ns() {
+        let elapsed = SpinSleeper::default().try_sleep_ns(1_000).unwrap();
+        assert!(elapsed >= Duration::from_nanos(1_000));
+        assert_eq!(TimeError::Overflow.to_string(), "sleep deadline overflow");
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_duration() {
+        assert_eq!(
+            time::Duration::milliseconds(5).into_sleep_duration(),
+            Duration::from_millis(5)
+        );
+        assert_eq!(
+            time::Duration::milliseconds(-5).into_sleep_duration(),
+            Duration::ZERO
+        );
+    }
+}
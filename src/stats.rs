@@ -0,0 +1,147 @@
+use super::*;
+
+/// Measurements of a single sleep returned by [`SpinSleeper::sleep_instrumented`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct SleepStats {
+    native_time: Duration,
+    spin_time: Duration,
+    overshoot: Duration,
+    spin_iterations: u64,
+}
+
+impl SleepStats {
+    /// Returns the time spent natively sleeping.
+    pub fn native_time(&self) -> Duration {
+        self.native_time
+    }
+
+    /// Returns the time spent spinning.
+    pub fn spin_time(&self) -> Duration {
+        self.spin_time
+    }
+
+    /// Returns how late the sleep finished relative to the requested deadline.
+    pub fn overshoot(&self) -> Duration {
+        self.overshoot
+    }
+
+    /// Returns the number of spin iterations.
+    pub fn spin_iterations(&self) -> u64 {
+        self.spin_iterations
+    }
+}
+
+impl SpinSleeper {
+    /// Sleeps like [`SpinSleeper::sleep`] returning [`SleepStats`] measurements.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::time::Duration;
+    /// let sleeper = spin_sleep::SpinSleeper::new(100_000);
+    /// let stats = sleeper.sleep_instrumented(Duration::from_millis(1));
+    /// println!(
+    ///     "native {:?}, spin {:?}, overshoot {:?}",
+    ///     stats.native_time(),
+    ///     stats.spin_time(),
+    ///     stats.overshoot()
+    /// );
+    /// ```
+    pub fn sleep_instrumented(self, duration: Duration) -> SleepStats {
+        let start = Instant::now();
+        let Some(deadline) = start.checked_add(duration) else {
+            // unrepresentable deadline, natively sleep like `sleep`
+            self.native_sleep(duration);
+            return SleepStats {
+                native_time: start.elapsed(),
+                spin_time: Duration::ZERO,
+                overshoot: Duration::ZERO,
+                spin_iterations: 0,
+            };
+        };
+        self.sleep_until_instrumented_from(deadline, start)
+    }
+
+    /// Sleeps like [`SpinSleeper::sleep_until`] returning [`SleepStats`] measurements.
+    pub fn sleep_until_instrumented(self, deadline: Instant) -> SleepStats {
+        self.sleep_until_instrumented_from(deadline, Instant::now())
+    }
+
+    fn sleep_until_instrumented_from(self, deadline: Instant, start: Instant) -> SleepStats {
+        let duration = deadline.saturating_duration_since(start);
+        let mut observer = StatsObserver::default();
+        let end = match duration.is_zero() {
+            true => Instant::now(),
+            false => self.spin_sleep_with(duration, deadline, &mut observer),
+        };
+        let spin_start = observer.spin_start.unwrap_or(end);
+
+        SleepStats {
+            native_time: spin_start - start,
+            spin_time: end - spin_start,
+            overshoot: end.saturating_duration_since(deadline),
+            spin_iterations: observer.spin_iterations,
+        }
+    }
+}
+
+/// Observes the spin phase of the internal sleep path, allowing it to be instrumented
+/// without duplication. The `()` observer does nothing & compiles away.
+pub(crate) trait SleepObserver {
+    /// Called once when the spin phase starts, after any native sleep.
+    #[inline]
+    fn spin_start(&mut self) {}
+
+    /// Called after each spin iteration.
+    #[inline]
+    fn spin(&mut self) {}
+}
+
+impl SleepObserver for () {}
+
+/// Records the spin phase for [`SleepStats`].
+#[derive(Default)]
+struct StatsObserver {
+    spin_start: Option<Instant>,
+    spin_iterations: u64,
+}
+
+impl SleepObserver for StatsObserver {
+    #[inline]
+    fn spin_start(&mut self) {
+        self.spin_start = Some(Instant::now());
+    }
+
+    #[inline]
+    fn spin(&mut self) {
+        self.spin_iterations += 1;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sleep_instrumented() {
+        let sleeper = SpinSleeper::new(100_000);
+
+        let stats = sleeper.sleep_instrumented(Duration::from_millis(1));
+        assert!(stats.native_time() >= Duration::from_micros(900));
+        assert!(stats.native_time() + stats.spin_time() >= Duration::from_millis(1));
+
+        // entirely spun
+        let stats = sleeper.sleep_instrumented(Duration::from_micros(50));
+        assert!(stats.spin_iterations() > 0);
+        assert!(stats.spin_time() >= Duration::from_micros(40));
+    }
+
+    /// Measures the configured sleep path, e.g. respecting `max_spins`.
+    #[test]
+    fn sleep_instrumented_max_spins() {
+        let sleeper = SpinSleeper::new(100_000).with_max_spins(0);
+        let stats = sleeper.sleep_instrumented(Duration::from_micros(50));
+        assert_eq!(stats.spin_iterations(), 0);
+        assert!(stats.native_time() + stats.spin_time() >= Duration::from_micros(50));
+    }
+}
@@ -0,0 +1,98 @@
+use super::*;
+use std::{fmt, time::SystemTime};
+
+/// Max native sleep between time jump checks in [`SpinSleeper::sleep_suspend_aware`].
+const CHECK_INTERVAL: Duration = Duration::from_millis(100);
+/// Max difference between wall clock & monotonic elapsed time before reporting a [`TimeJump`].
+const JUMP_TOLERANCE: Duration = Duration::from_millis(500);
+
+impl SpinSleeper {
+    /// Sleeps for the given duration like [`SpinSleeper::sleep`], but returns early with a
+    /// [`TimeJump`] error if the system appears to have been suspended during the sleep.
+    ///
+    /// On some platforms [`Instant`] does not advance while the system is suspended
+    /// (e.g. laptop sleep), so a sleep spanning a suspension will mistime. This method natively
+    /// sleeps in chunks of at most 100ms, cross-checking elapsed [`Instant`] time against
+    /// [`SystemTime`] after each. If they differ by more than 500ms the sleep is abandoned.
+    ///
+    /// Since [`SystemTime`] is not monotonic, wall clock adjustments (e.g. NTP, manual changes)
+    /// during the sleep are also reported as a [`TimeJump`].
+    ///
+    /// If the deadline, now plus `duration`, is unrepresentable, e.g. `Duration::MAX`,
+    /// natively sleeps in chunks until a time jump is detected, i.e. effectively forever.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// let sleeper = spin_sleep::SpinSleeper::default();
+    /// if let Err(jump) = sleeper.sleep_suspend_aware(Duration::from_secs(1)) {
+    ///     eprintln!("{jump}, rescheduling");
+    /// }
+    /// ```
+    pub fn sleep_suspend_aware(self, duration: Duration) -> Result<(), TimeJump> {
+        let start = Instant::now();
+        let wall_start = SystemTime::now();
+        // `None` if unrepresentable, i.e. never reached
+        let deadline = start.checked_add(duration);
+
+        loop {
+            let now = Instant::now();
+            let monotonic_elapsed = now - start;
+            let wall_elapsed = SystemTime::now()
+                .duration_since(wall_start)
+                .unwrap_or_default();
+            if wall_elapsed.abs_diff(monotonic_elapsed) > JUMP_TOLERANCE {
+                return Err(TimeJump {
+                    monotonic_elapsed,
+                    wall_elapsed,
+                });
+            }
+
+            if let Some(deadline) = deadline {
+                if deadline.saturating_duration_since(now) <= CHECK_INTERVAL {
+                    self.sleep_until(deadline);
+                    return Ok(());
+                }
+            }
+            self.native_sleep(CHECK_INTERVAL);
+        }
+    }
+}
+
+/// Error returned by [`SpinSleeper::sleep_suspend_aware`] when elapsed wall clock time
+/// diverged from elapsed monotonic time, e.g. because the system was suspended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimeJump {
+    /// Elapsed [`Instant`] time when the jump was detected.
+    pub monotonic_elapsed: Duration,
+    /// Elapsed [`SystemTime`] when the jump was detected.
+    /// Zero if the wall clock moved backwards.
+    pub wall_elapsed: Duration,
+}
+
+impl fmt::Display for TimeJump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "time jump detected: {:?} monotonic elapsed, {:?} wall clock elapsed",
+            self.monotonic_elapsed, self.wall_elapsed
+        )
+    }
+}
+
+impl std::error::Error for TimeJump {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn no_jump() {
+        let sleeper = SpinSleeper::new(0);
+        let start = Instant::now();
+        sleeper
+            .sleep_suspend_aware(Duration::from_millis(5))
+            .unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(5));
+    }
+}
@@ -0,0 +1,32 @@
+use super::*;
+
+impl SpinSleeper {
+    /// [`SpinSleeper::spin_sleep`] wrapped in a `trace` level `spin_sleep` span recording the
+    /// requested duration, native/spin split & overshoot. Also emits a `trace` event with
+    /// the same fields once complete, so sleeps are visible without span close logging.
+    #[cold]
+    pub(crate) fn spin_sleep_traced(self, duration: Duration, deadline: Instant) -> Instant {
+        let native = self.native_portion(duration);
+        let span = tracing::trace_span!(
+            "spin_sleep",
+            requested = ?duration,
+            native = ?native,
+            spin = ?(duration - native),
+            overshoot = tracing::field::Empty,
+        );
+        let _enter = span.enter();
+
+        let end = self.spin_sleep(duration, deadline);
+
+        let overshoot = end.saturating_duration_since(deadline);
+        span.record("overshoot", tracing::field::debug(overshoot));
+        tracing::trace!(
+            requested = ?duration,
+            native = ?native,
+            spin = ?(duration - native),
+            overshoot = ?overshoot,
+            "slept"
+        );
+        end
+    }
+}
@@ -1,7 +1,18 @@
-use std::{mem, ops::Neg, ptr::null, sync::OnceLock, time::Duration};
+//! Windows specific functionality.
+use std::{
+    mem,
+    ops::Neg,
+    ptr::null,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        OnceLock,
+    },
+    time::{Duration, Instant},
+};
 use windows_sys::Win32::{
     Foundation::{CloseHandle, FALSE},
     Media::{timeBeginPeriod, timeEndPeriod, timeGetDevCaps, TIMECAPS, TIMERR_NOERROR},
+    System::Performance::{QueryPerformanceCounter, QueryPerformanceFrequency},
     System::Threading::{
         CreateWaitableTimerExW, SetWaitableTimer, WaitForSingleObject,
         CREATE_WAITABLE_TIMER_HIGH_RESOLUTION, INFINITE, TIMER_ALL_ACCESS,
@@ -9,9 +20,13 @@ use windows_sys::Win32::{
 };
 
 #[inline]
-pub fn native_sleep(duration: Duration) {
+pub(crate) fn native_sleep(duration: Duration, timer_period: bool) {
     if high_res_sleep(&duration).is_err() {
         // fallback for OS earlier than Windows 10, version 1803.
+        if !timer_period || TIMER_RESOLUTION_GUARDS.load(Ordering::Relaxed) > 0 {
+            std::thread::sleep(duration);
+            return;
+        }
         let min_time_period = min_time_period();
         unsafe {
             timeBeginPeriod(min_time_period);
@@ -21,9 +36,61 @@ pub fn native_sleep(duration: Duration) {
     }
 }
 
+/// Number of live [`TimerResolutionGuard`]s.
+static TIMER_RESOLUTION_GUARDS: AtomicUsize = AtomicUsize::new(0);
+
+/// Raises the global timer resolution, using `timeBeginPeriod`, until dropped.
+///
+/// On Windows versions without high resolution waitable timers (< Windows 10, version 1803)
+/// native sleep otherwise calls `timeBeginPeriod`/`timeEndPeriod` around every sleep.
+/// While a guard is alive these calls are skipped, reducing overhead for high frequency loops.
+///
+/// **This affects the whole process**, and on older Windows versions the whole system,
+/// increasing power usage. So should only be held for the duration of a loop that needs it.
+///
+/// # Example
+/// ```no_run
+/// use spin_sleep::{windows::TimerResolutionGuard, SpinSleeper};
+/// # use std::time::Duration;
+///
+/// let _timer_resolution = TimerResolutionGuard::new();
+/// let sleeper = SpinSleeper::default();
+/// for _ in 0..1000 {
+///     sleeper.sleep(Duration::from_millis(2));
+/// }
+/// ```
+#[derive(Debug)]
+#[must_use = "timer resolution is restored when the guard is dropped"]
+pub struct TimerResolutionGuard {
+    period: u32,
+}
+
+impl TimerResolutionGuard {
+    /// Raises the global timer resolution to the minimum supported period.
+    pub fn new() -> Self {
+        let period = min_time_period();
+        unsafe { timeBeginPeriod(period) };
+        TIMER_RESOLUTION_GUARDS.fetch_add(1, Ordering::Relaxed);
+        Self { period }
+    }
+}
+
+impl Default for TimerResolutionGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for TimerResolutionGuard {
+    fn drop(&mut self) {
+        TIMER_RESOLUTION_GUARDS.fetch_sub(1, Ordering::Relaxed);
+        unsafe { timeEndPeriod(self.period) };
+    }
+}
+
 #[inline]
 pub(crate) fn sleep_accuracy() -> u32 {
-    if HIGH_RES_TIMER.with(|t| t.is_ok()) {
+    if high_res_timer_available() {
         // high resolution timer is fast on average but has high maximums
         // e.g. `average: 154.7µs, best: 2.6µs, worst: 729.5µs`
         //
@@ -34,6 +101,83 @@ pub(crate) fn sleep_accuracy() -> u32 {
     }
 }
 
+/// Measures the discrepancy between a high resolution waitable timer wait and the
+/// `Instant` measured elapsed time for the same request.
+///
+/// The waitable timer measures against the system timer, whereas spinning measures against
+/// `Instant`, so skew between them biases accuracy. This returns the smallest `Instant` measured
+/// lateness of several short timer waits, i.e. excluding most scheduling latency.
+///
+/// Returns zero if the high resolution timer is not available (< Windows 10, version 1803).
+pub fn timer_clock_skew() -> Duration {
+    const REQUEST: Duration = Duration::from_millis(1);
+
+    let mut skew = Duration::MAX;
+    for _ in 0..10 {
+        let start = Instant::now();
+        if high_res_sleep(&REQUEST).is_err() {
+            return Duration::ZERO;
+        }
+        skew = skew.min(start.elapsed().saturating_sub(REQUEST));
+    }
+    skew
+}
+
+/// Spins, using the given strategy, until the `deadline` reading `QueryPerformanceCounter`
+/// directly instead of `Instant::now()`.
+#[inline]
+pub(crate) fn busy_wait_until_qpc<O: crate::SleepObserver>(
+    deadline: Instant,
+    strategy: crate::SpinStrategy,
+    observer: &mut O,
+) -> Instant {
+    let freq = qpc_frequency();
+    let start = qpc();
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    let remaining_counts = (remaining.as_nanos() * freq as u128 / 1_000_000_000) as i64;
+    let end = start.saturating_add(remaining_counts);
+
+    loop {
+        let now = qpc();
+        if now >= end {
+            break;
+        }
+        let remaining_ns = (end - now) as u128 * 1_000_000_000 / freq as u128;
+        strategy.spin(Duration::from_nanos(remaining_ns as u64));
+        observer.spin();
+    }
+    Instant::now()
+}
+
+#[inline]
+fn qpc() -> i64 {
+    let mut count = 0;
+    unsafe { QueryPerformanceCounter(&mut count) };
+    count
+}
+
+/// `QueryPerformanceCounter` counts per second.
+fn qpc_frequency() -> i64 {
+    static QPC_FREQUENCY: OnceLock<i64> = OnceLock::new();
+
+    *QPC_FREQUENCY.get_or_init(|| {
+        let mut freq = 0;
+        unsafe { QueryPerformanceFrequency(&mut freq) };
+        freq.max(1)
+    })
+}
+
+/// Returns the resolution of [`native_sleep`], sub-microsecond if the high resolution
+/// timer is available, otherwise the minimum `timeBeginPeriod`.
+pub(crate) fn native_sleep_resolution() -> Duration {
+    if high_res_timer_available() {
+        // 100ns intervals
+        Duration::from_nanos(100)
+    } else {
+        Duration::from_millis(min_time_period().into())
+    }
+}
+
 /// Minimum time period for use with `timeBeginPeriod` & `timeEndPeriod`.
 fn min_time_period() -> u32 {
     static MIN_TIME_PERIOD: OnceLock<u32> = OnceLock::new();
@@ -53,6 +197,13 @@ fn min_time_period() -> u32 {
     })
 }
 
+/// Returns `true` if the high resolution waitable timer is available
+/// (>= Windows 10, version 1803).
+#[inline]
+pub(crate) fn high_res_timer_available() -> bool {
+    HIGH_RES_TIMER.with(|t| t.is_ok())
+}
+
 thread_local! {
     static HIGH_RES_TIMER: Result<WaitableTimer, ()> = WaitableTimer::try_high_resolution();
 }
@@ -0,0 +1,160 @@
+use spin_sleep::{MissedTickBehavior, SpinSleeper};
+use std::time::{Duration, Instant};
+
+/// Resolution of the tokio timer, tokio sleeps may overshoot by up to this much.
+const TOKIO_TIMER_RESOLUTION: Duration = Duration::from_millis(1);
+
+/// Creates new [`AsyncInterval`] that yields with interval of `period`.
+/// The first tick completes immediately.
+///
+/// # Panics
+/// Panics if `period` is zero.
+///
+/// # Example
+/// ```no_run
+/// # use std::time::Duration;
+/// # fn compute_something() {}
+/// # async fn example() {
+/// let mut interval = spin_sleep_util::async_interval(Duration::from_secs(1) / 144);
+/// loop {
+///     compute_something();
+///     interval.tick().await;
+/// }
+/// # }
+/// ```
+#[track_caller]
+pub fn async_interval(period: Duration) -> AsyncInterval {
+    async_interval_at(Instant::now(), period)
+}
+
+/// Creates new [`AsyncInterval`] that yields with interval of `period` with the
+/// first tick completing at `start`.
+///
+/// # Panics
+/// Panics if `period` is zero.
+#[track_caller]
+pub fn async_interval_at(start: Instant, period: Duration) -> AsyncInterval {
+    assert!(period > Duration::ZERO, "`period` must be non-zero.");
+    AsyncInterval {
+        next_tick: start,
+        period,
+        missed_tick_behavior: <_>::default(),
+        sleeper: <_>::default(),
+    }
+}
+
+/// Async interval returned by [`async_interval`] and [`async_interval_at`].
+///
+/// Ticks sleep the bulk of the wait using `tokio::time::sleep`, not blocking the executor,
+/// then precisely sleep the final section, including spinning, inside `spawn_blocking`.
+///
+/// Requires a tokio runtime with the time driver enabled.
+#[derive(Debug)]
+pub struct AsyncInterval {
+    next_tick: Instant,
+    period: Duration,
+    missed_tick_behavior: MissedTickBehavior,
+    sleeper: SpinSleeper,
+}
+
+impl AsyncInterval {
+    /// Waits until the next scheduled tick.
+    ///
+    /// If the tick is in the past will return without waiting
+    /// computing the next tick based on the configured [`MissedTickBehavior`].
+    ///
+    /// Returns the tick time.
+    ///
+    /// If the returned future is dropped before completing the tick is not consumed,
+    /// so a following tick will wait for the same time.
+    pub async fn tick(&mut self) -> Instant {
+        let tick = self.next_tick;
+        let now = Instant::now();
+
+        if now > tick {
+            // missed tick
+            self.next_tick = self.missed_tick_behavior.next_tick(tick, now, self.period);
+            return tick;
+        }
+
+        // leave the precise part, plus tokio timer inaccuracy, for the blocking sleep
+        let async_portion = self
+            .sleeper
+            .native_portion(tick - now)
+            .saturating_sub(TOKIO_TIMER_RESOLUTION);
+        if !async_portion.is_zero() {
+            tokio::time::sleep_until((now + async_portion).into()).await;
+        }
+
+        if Instant::now() < tick {
+            let sleeper = self.sleeper;
+            tokio::task::spawn_blocking(move || sleeper.sleep_until(tick))
+                .await
+                .expect("spin sleep task panicked");
+        }
+
+        self.next_tick = tick + self.period;
+        tick
+    }
+
+    /// Returns the instant of the next scheduled tick.
+    pub fn next_tick(&self) -> Instant {
+        self.next_tick
+    }
+
+    /// Returns the period of the interval.
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// Returns the [`MissedTickBehavior`] strategy that is currently being used.
+    pub fn missed_tick_behavior(&self) -> MissedTickBehavior {
+        self.missed_tick_behavior
+    }
+
+    /// Returns `Self` with the specified [`MissedTickBehavior`] strategy.
+    pub fn with_missed_tick_behavior(mut self, behavior: MissedTickBehavior) -> Self {
+        self.missed_tick_behavior = behavior;
+        self
+    }
+
+    /// Returns the configured [`SpinSleeper`].
+    pub fn spin_sleeper(&self) -> SpinSleeper {
+        self.sleeper
+    }
+
+    /// Returns `Self` with the specified [`SpinSleeper`].
+    pub fn with_spin_sleeper(mut self, sleeper: SpinSleeper) -> Self {
+        self.sleeper = sleeper;
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[tokio::test]
+    async fn tick() {
+        let period = Duration::from_millis(5);
+        let start = Instant::now();
+        let mut interval = async_interval_at(start, period);
+
+        assert_eq!(interval.tick().await, start);
+        for n in 1..=3 {
+            let tick = interval.tick().await;
+            assert_eq!(tick, start + period * n);
+            assert!(Instant::now() >= tick);
+        }
+    }
+
+    #[tokio::test]
+    async fn missed_tick_skip() {
+        let period = Duration::from_millis(5);
+        let start = Instant::now() - Duration::from_millis(12);
+        let mut interval = async_interval_at(start, period);
+
+        assert_eq!(interval.tick().await, start);
+        assert_eq!(interval.next_tick(), start + period * 3);
+    }
+}
@@ -1,5 +1,13 @@
-use spin_sleep::SpinSleeper;
-use std::time::{Duration, Instant};
+use crate::RateReporter;
+pub use spin_sleep::MissedTickBehavior;
+use spin_sleep::{SleepEnd, SpinSleeper};
+use std::{
+    collections::hash_map::RandomState,
+    fmt,
+    hash::{BuildHasher, Hasher},
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
 
 /// Creates new [`Interval`] that spin-sleeps with interval of `period`. The first
 /// tick returns immediately. The default [`MissedTickBehavior`] is
@@ -26,11 +34,74 @@ pub fn interval(period: Duration) -> Interval {
 #[track_caller]
 pub fn interval_at(start: Instant, period: Duration) -> Interval {
     assert!(period > Duration::ZERO, "`period` must be non-zero.");
+    new_interval(start, period)
+}
+
+/// Creates new [`Interval`] like [`interval`] but returns an error instead of panicking
+/// if `period` is zero.
+///
+/// # Example
+/// ```
+/// use spin_sleep_util::{try_interval, IntervalError};
+/// # use std::time::Duration;
+///
+/// assert!(try_interval(Duration::from_millis(10)).is_ok());
+/// assert_eq!(
+///     try_interval(Duration::ZERO).err(),
+///     Some(IntervalError::ZeroPeriod)
+/// );
+/// ```
+pub fn try_interval(period: Duration) -> Result<Interval, IntervalError> {
+    try_interval_at(Instant::now(), period)
+}
+
+/// Creates new [`Interval`] like [`interval_at`] but returns an error instead of panicking
+/// if `period` is zero.
+pub fn try_interval_at(start: Instant, period: Duration) -> Result<Interval, IntervalError> {
+    if period.is_zero() {
+        return Err(IntervalError::ZeroPeriod);
+    }
+    Ok(new_interval(start, period))
+}
+
+/// Error creating an [`Interval`], see [`try_interval`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IntervalError {
+    /// The `period` was zero.
+    ZeroPeriod,
+}
+
+impl fmt::Display for IntervalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroPeriod => f.write_str("`period` must be non-zero"),
+        }
+    }
+}
+
+impl std::error::Error for IntervalError {}
+
+/// Max ticks returned by a single [`Interval::collect_ticks_until`] call.
+pub const MAX_COLLECTED_TICKS: usize = 1 << 20;
+
+#[inline]
+fn new_interval(start: Instant, period: Duration) -> Interval {
     Interval {
         next_tick: start,
         period,
         missed_tick_behavior: <_>::default(),
         sleeper: <_>::default(),
+        rate_reporter: None,
+        paused_at: None,
+        tick_count: 0,
+        missed_ticks: 0,
+        stats_start: Instant::now(),
+        cumulative_drift: Duration::ZERO,
+        fire_immediately: true,
+        phase_offset: Duration::ZERO,
+        jitter: Duration::ZERO,
+        jitter_seed: 0,
     }
 }
 
@@ -41,6 +112,17 @@ pub struct Interval {
     period: Duration,
     missed_tick_behavior: MissedTickBehavior,
     sleeper: SpinSleeper,
+    rate_reporter: Option<RateReporter>,
+    paused_at: Option<Instant>,
+    tick_count: u64,
+    missed_ticks: u64,
+    stats_start: Instant,
+    cumulative_drift: Duration,
+    fire_immediately: bool,
+    phase_offset: Duration,
+    jitter: Duration,
+    /// xorshift state for jitter offsets.
+    jitter_seed: u64,
 }
 
 impl Interval {
@@ -51,9 +133,94 @@ impl Interval {
     ///
     /// Returns the tick time.
     pub fn tick(&mut self) -> Instant {
+        self.tick_with_spin(true).scheduled()
+    }
+
+    /// [`Self::tick`] returning a [`TickOutcome`] detailing whether the tick slept until the
+    /// scheduled time or was missed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use spin_sleep_util::TickOutcome;
+    /// # use std::time::Duration;
+    /// # fn compute_something() {}
+    ///
+    /// let mut interval = spin_sleep_util::interval(Duration::from_secs(1) / 144);
+    /// loop {
+    ///     compute_something();
+    ///
+    ///     if let TickOutcome::Missed { skipped, .. } = interval.tick_detailed() {
+    ///         eprintln!("missed tick, skipped {skipped}");
+    ///     }
+    /// }
+    /// ```
+    pub fn tick_detailed(&mut self) -> TickOutcome {
         self.tick_with_spin(true)
     }
 
+    /// [`Self::tick`] also returning the lateness, the time elapsed since the scheduled tick
+    /// measured after sleeping.
+    ///
+    /// For on-time ticks this is small, just the sleep overshoot plus any
+    /// [jitter](Self::with_jitter). For missed ticks it is how far behind schedule the call was.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # fn compute_something() {}
+    /// let mut interval = spin_sleep_util::interval(Duration::from_secs(1) / 144);
+    /// loop {
+    ///     compute_something();
+    ///
+    ///     let (_, lateness) = interval.tick_with_lateness();
+    ///     if lateness > Duration::from_millis(2) {
+    ///         eprintln!("running {lateness:?} behind");
+    ///     }
+    /// }
+    /// ```
+    pub fn tick_with_lateness(&mut self) -> (Instant, Duration) {
+        let tick = self.tick();
+        (tick, tick.elapsed())
+    }
+
+    /// [`Self::tick`] returning `None` promptly if `cancel` is set, before or during the wait.
+    ///
+    /// `cancel` is checked using [`SpinSleeper::sleep_until_interruptible`]. When cancelled
+    /// the scheduled tick is not consumed, so a following tick will wait for the same time.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::sync::atomic::AtomicBool;
+    /// # use std::time::Duration;
+    /// # fn compute_something() {}
+    ///
+    /// let shutdown = AtomicBool::new(false);
+    /// let mut interval = spin_sleep_util::interval(Duration::from_secs(1) / 144);
+    /// while interval.tick_or(&shutdown).is_some() {
+    ///     compute_something();
+    /// }
+    /// ```
+    pub fn tick_or(&mut self, cancel: &AtomicBool) -> Option<Instant> {
+        if cancel.load(Ordering::Acquire) {
+            return None;
+        }
+        let tick = self.next_tick;
+        if Instant::now() > tick {
+            return Some(self.tick_with_spin(true).scheduled());
+        }
+
+        let wake = tick + self.next_jitter();
+        match self.sleeper.sleep_until_interruptible(wake, cancel) {
+            SleepEnd::Completed { overshoot } => {
+                self.tick_count += 1;
+                self.cumulative_drift += overshoot;
+                self.next_tick = tick + self.period;
+                Some(tick)
+            }
+            SleepEnd::Interrupted { .. } => None,
+        }
+    }
+
     /// Use [`spin_sleep::native_sleep`] to sleep until the next scheduled tick.
     /// **Does not spin.**
     ///
@@ -62,32 +229,418 @@ impl Interval {
     ///
     /// Returns the tick time.
     pub fn tick_no_spin(&mut self) -> Instant {
-        self.tick_with_spin(false)
+        self.tick_with_spin(false).scheduled()
+    }
+
+    /// [`Self::tick`] and increment the embedded [`RateReporter`], see [`Self::with_rate_report`].
+    ///
+    /// Returns the tick time and the rate report, if due.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # fn compute_something() {}
+    /// # fn update_fps(fps: f64) {}
+    /// let mut interval = spin_sleep_util::interval(Duration::from_secs(1) / 144)
+    ///     .with_rate_report(Duration::from_secs(1));
+    ///
+    /// loop {
+    ///     compute_something();
+    ///
+    ///     if let (_, Some(fps)) = interval.tick_and_report() {
+    ///         update_fps(fps);
+    ///     }
+    /// }
+    /// ```
+    pub fn tick_and_report(&mut self) -> (Instant, Option<f64>) {
+        let tick = self.tick();
+        let report = self
+            .rate_reporter
+            .as_mut()
+            .and_then(|r| r.increment_and_report());
+        (tick, report)
+    }
+
+    /// Ticks returning the number of periods due since the last tick, capped at `max_steps`.
+    /// Intended for fixed-timestep simulations that should run a step per period.
+    ///
+    /// If the next tick is in the future, sleeps until it & returns `1`.
+    /// Otherwise returns without sleeping the number of whole periods elapsed since the
+    /// missed tick, including it, capped at `max_steps`. The next tick is then scheduled
+    /// on the period grid after the current time, so periods beyond `max_steps` are dropped.
+    ///
+    /// If `max_steps` is zero returns `0` immediately without ticking.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # fn simulate_step(_: Duration) {}
+    /// # fn render() {}
+    /// let step = Duration::from_secs(1) / 60;
+    /// let mut interval = spin_sleep_util::interval(step);
+    /// loop {
+    ///     for _ in 0..interval.tick_steps(5) {
+    ///         simulate_step(step);
+    ///     }
+    ///     render();
+    /// }
+    /// ```
+    pub fn tick_steps(&mut self, max_steps: u32) -> u32 {
+        if max_steps == 0 {
+            return 0;
+        }
+        let tick = self.next_tick;
+        let now = Instant::now();
+        self.tick_count += 1;
+
+        if now > tick {
+            // missed tick(s)
+            self.cumulative_drift += now - tick;
+            let periods = (now - tick).as_nanos() / self.period.as_nanos() + 1;
+            let periods = u64::try_from(periods).unwrap_or(u64::MAX);
+            self.missed_ticks = self
+                .missed_ticks
+                .saturating_add(periods.saturating_sub(max_steps.into()));
+            self.next_tick = MissedTickBehavior::Skip.next_tick(tick, now, self.period);
+            return u32::try_from(periods).map_or(max_steps, |p| p.min(max_steps));
+        }
+
+        let elapsed = self.sleeper.sleep_until_with_now(tick, now);
+        self.cumulative_drift += elapsed.saturating_sub(tick - now);
+        self.next_tick = tick + self.period;
+        1
     }
 
     #[inline]
-    fn tick_with_spin(&mut self, spin: bool) -> Instant {
+    fn tick_with_spin(&mut self, spin: bool) -> TickOutcome {
         let tick = self.next_tick;
         let now = Instant::now();
+        self.tick_count += 1;
 
         if now > tick {
-            // missed tick
-            self.next_tick = self.missed_tick_behavior.next_tick(tick, now, self.period);
-            return tick;
+            return self.tick_missed(tick, now);
         }
 
-        match spin {
-            true => self.sleeper.sleep(tick - now),
-            false => spin_sleep::native_sleep(tick - now),
+        let wake = tick + self.next_jitter();
+        let slept = wake - now;
+        let elapsed = match spin {
+            true => self.sleeper.sleep_until_with_now(wake, now),
+            false => {
+                spin_sleep::native_sleep(slept);
+                now.elapsed()
+            }
         };
+        self.cumulative_drift += elapsed.saturating_sub(slept);
 
         self.next_tick = tick + self.period;
-        tick
+        TickOutcome::OnTime {
+            scheduled: tick,
+            slept,
+        }
     }
 
-    /// Resets the scheduled next tick to one period after the current time.
+    /// Handles the missed `tick`, scheduling the next according to the
+    /// [`MissedTickBehavior`].
+    #[inline]
+    fn tick_missed(&mut self, tick: Instant, now: Instant) -> TickOutcome {
+        self.cumulative_drift += now - tick;
+        self.next_tick = self.missed_tick_behavior.next_tick(tick, now, self.period);
+        let skipped = match self.missed_tick_behavior {
+            MissedTickBehavior::Burst => 0,
+            _ => ((now - tick).as_nanos() / self.period.as_nanos())
+                .try_into()
+                .unwrap_or(u64::MAX),
+        };
+        let missed = match self.missed_tick_behavior {
+            MissedTickBehavior::Skip => skipped,
+            _ => 1,
+        };
+        self.missed_ticks = self.missed_ticks.saturating_add(missed);
+        TickOutcome::Missed {
+            scheduled: tick,
+            skipped,
+        }
+    }
+
+    /// Returns the next tick, advancing the schedule, if it is due. Otherwise returns `None`.
+    /// **Never sleeps or spins.**
+    ///
+    /// A due tick polled less than a period late is on time, as polls will rarely land exactly
+    /// on the tick. Later ticks are handled according to the configured
+    /// [`MissedTickBehavior`], as with [`Self::tick`]. Useful to integrate the schedule into an existing event loop that
+    /// does its own waiting, e.g. until [`Self::next_tick`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # fn handle_events() {}
+    /// # fn render() {}
+    /// let mut interval = spin_sleep_util::interval(Duration::from_secs(1) / 60);
+    /// loop {
+    ///     handle_events();
+    ///     if interval.poll_tick().is_some() {
+    ///         render();
+    ///     }
+    /// }
+    /// ```
+    pub fn poll_tick(&mut self) -> Option<Instant> {
+        let tick = self.next_tick;
+        let now = Instant::now();
+        if now < tick {
+            return None;
+        }
+        self.tick_count += 1;
+        if now - tick >= self.period {
+            return Some(self.tick_missed(tick, now).scheduled());
+        }
+        self.cumulative_drift += now - tick;
+        self.next_tick = tick + self.period;
+        Some(tick)
+    }
+
+    /// Resets the scheduled next tick to one period after the current time,
+    /// less any [phase offset](Self::with_phase_offset).
+    ///
+    /// Also resets the [realized rate](Self::realized_rate) &
+    /// [cumulative drift](Self::cumulative_drift).
     pub fn reset(&mut self) {
-        self.next_tick = Instant::now() + self.period;
+        self.next_tick = Instant::now() + self.period.saturating_sub(self.phase_offset);
+        self.reset_stats();
+    }
+
+    /// Resets the scheduled next tick to `instant`, e.g. to align ticks to a specific time
+    /// when resuming. Following ticks are scheduled every period after it.
+    ///
+    /// Also resets the [realized rate](Self::realized_rate) &
+    /// [cumulative drift](Self::cumulative_drift).
+    ///
+    /// # Example
+    /// ```
+    /// # use std::time::{Duration, Instant};
+    /// let mut i = spin_sleep_util::interval(Duration::from_millis(20));
+    /// let at = Instant::now() + Duration::from_millis(5);
+    /// i.reset_at(at);
+    /// assert_eq!(i.next_tick(), at);
+    /// ```
+    pub fn reset_at(&mut self, instant: Instant) {
+        self.next_tick = instant;
+        self.reset_stats();
+    }
+
+    /// Resets the scheduled next tick to the current time, so the next tick returns
+    /// immediately. Following ticks are scheduled every period after it.
+    ///
+    /// Also resets the [realized rate](Self::realized_rate) &
+    /// [cumulative drift](Self::cumulative_drift).
+    pub fn reset_immediately(&mut self) {
+        self.reset_at(Instant::now());
+    }
+
+    /// Resets the [realized rate](Self::realized_rate), [tick count](Self::tick_count),
+    /// [missed ticks](Self::missed_ticks) & [cumulative drift](Self::cumulative_drift)
+    /// without affecting the schedule.
+    pub fn reset_stats(&mut self) {
+        self.tick_count = 0;
+        self.missed_ticks = 0;
+        self.stats_start = Instant::now();
+        self.cumulative_drift = Duration::ZERO;
+    }
+
+    /// Returns the number of ticks since construction or [stats reset](Self::reset_stats).
+    ///
+    /// Unaffected by [`Self::set_period`], so may be used for long-run totals.
+    pub fn tick_count(&self) -> u64 {
+        self.tick_count
+    }
+
+    /// Returns the number of scheduled ticks missed since construction or
+    /// [reset](Self::reset_missed_ticks).
+    ///
+    /// A tick is missed if it is already in the past when waited for. With
+    /// [`MissedTickBehavior::Skip`] only the whole periods jumped over are counted, as the
+    /// late tick itself is still returned, so this is a true dropped frame count.
+    /// [`Self::tick_steps`] likewise counts only the due periods beyond `max_steps`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # fn render() {}
+    /// # fn show_dropped_frames(_: u64) {}
+    /// let mut interval = spin_sleep_util::interval(Duration::from_secs(1) / 60);
+    /// loop {
+    ///     render();
+    ///     interval.tick();
+    ///     show_dropped_frames(interval.missed_ticks());
+    /// }
+    /// ```
+    pub fn missed_ticks(&self) -> u64 {
+        self.missed_ticks
+    }
+
+    /// Resets the [missed ticks](Self::missed_ticks) count to zero.
+    pub fn reset_missed_ticks(&mut self) {
+        self.missed_ticks = 0;
+    }
+
+    /// Returns the sum of how late each tick returned relative to its scheduled time,
+    /// since construction or [reset](Self::reset).
+    ///
+    /// For [`MissedTickBehavior::Skip`] & [`MissedTickBehavior::Burst`] this quantifies
+    /// realized jitter. For [`MissedTickBehavior::Delay`] it quantifies accumulated phase slip.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::time::Duration;
+    /// let mut interval = spin_sleep_util::interval(Duration::from_millis(1));
+    /// for _ in 0..5 {
+    ///     interval.tick();
+    /// }
+    /// println!("drift after 5 ticks: {:?}", interval.cumulative_drift());
+    /// ```
+    pub fn cumulative_drift(&self) -> Duration {
+        self.cumulative_drift
+    }
+
+    /// Resets the [cumulative drift](Self::cumulative_drift) to zero.
+    pub fn reset_cumulative_drift(&mut self) {
+        self.cumulative_drift = Duration::ZERO;
+    }
+
+    /// Returns the realized rate of ticks per second, the number of ticks since
+    /// construction or [reset](Self::reset) divided by the elapsed time.
+    ///
+    /// A realized rate significantly lower than the [target rate](Self::target_rate)
+    /// indicates the ticking loop is too slow for the period.
+    pub fn realized_rate(&self) -> f64 {
+        self.tick_count as f64 / self.stats_start.elapsed().as_secs_f64()
+    }
+
+    /// Returns the target rate of ticks per second, derived from the period.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep_util::interval;
+    /// # use std::time::Duration;
+    ///
+    /// let i = interval(Duration::from_millis(20));
+    /// assert_eq!(i.target_rate(), 50.0);
+    /// ```
+    pub fn target_rate(&self) -> f64 {
+        1.0 / self.period.as_secs_f64()
+    }
+
+    /// Advances the scheduled next tick by `n` periods without sleeping.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep_util::interval_at;
+    /// # use std::time::{Duration, Instant};
+    ///
+    /// let start = Instant::now();
+    /// let mut i = interval_at(start, Duration::from_millis(20));
+    /// i.skip(3);
+    /// assert_eq!(i.next_tick(), start + Duration::from_millis(60));
+    /// ```
+    pub fn skip(&mut self, n: u32) {
+        self.next_tick += self.period * n;
+    }
+
+    /// Advances the scheduled next tick, without sleeping, to the first tick at or after `at`
+    /// keeping ticks aligned to the existing schedule.
+    ///
+    /// Has no effect if the next tick is already at or after `at`.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep_util::interval_at;
+    /// # use std::time::{Duration, Instant};
+    ///
+    /// let start = Instant::now();
+    /// let mut i = interval_at(start, Duration::from_millis(20));
+    /// i.skip_to(start + Duration::from_millis(50));
+    /// assert_eq!(i.next_tick(), start + Duration::from_millis(60));
+    /// ```
+    pub fn skip_to(&mut self, at: Instant) {
+        if at <= self.next_tick {
+            return;
+        }
+        let period_ns = self.period.as_nanos();
+        let skip_ns = (at - self.next_tick).as_nanos().div_ceil(period_ns) * period_ns;
+        self.next_tick += Duration::from_nanos(
+            skip_ns
+                .try_into()
+                .expect("too much time to skip_to, more than 584 years"),
+        );
+    }
+
+    /// Returns, without sleeping, all scheduled tick instants from the next tick up to and
+    /// including the last tick at or before `end`, advancing the next tick past `end`.
+    ///
+    /// Useful to process buffered data as if it had ticked at rate, e.g. resampling
+    /// a sensor stream to a fixed grid.
+    ///
+    /// At most [`MAX_COLLECTED_TICKS`] are returned. If capped the next tick is the first
+    /// uncollected tick, so calling again continues the collection.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep_util::interval_at;
+    /// # use std::time::{Duration, Instant};
+    ///
+    /// let start = Instant::now();
+    /// let period = Duration::from_millis(20);
+    /// let mut i = interval_at(start, period);
+    /// let ticks = i.collect_ticks_until(start + Duration::from_millis(50));
+    /// assert_eq!(ticks, [start, start + period, start + period * 2]);
+    /// assert_eq!(i.next_tick(), start + period * 3);
+    /// ```
+    pub fn collect_ticks_until(&mut self, end: Instant) -> Vec<Instant> {
+        let mut ticks = Vec::new();
+        while self.next_tick <= end && ticks.len() < MAX_COLLECTED_TICKS {
+            ticks.push(self.next_tick);
+            self.next_tick += self.period;
+        }
+        ticks
+    }
+
+    /// Pauses the interval, see [`Self::resume`].
+    ///
+    /// Has no effect if already paused.
+    pub fn pause(&mut self) {
+        if self.paused_at.is_none() {
+            self.paused_at = Some(Instant::now());
+        }
+    }
+
+    /// Resumes a [paused](Self::pause) interval shifting the next scheduled tick forward by the
+    /// paused duration. This preserves the phase of ticks while avoiding a flood of
+    /// missed ticks.
+    ///
+    /// Has no effect if not paused.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # fn paused_by_user() -> bool { false }
+    /// let mut interval = spin_sleep_util::interval(Duration::from_millis(20));
+    /// loop {
+    ///     if paused_by_user() {
+    ///         interval.pause();
+    ///         while paused_by_user() {}
+    ///         interval.resume();
+    ///     }
+    ///     interval.tick();
+    /// }
+    /// ```
+    pub fn resume(&mut self) {
+        if let Some(paused_at) = self.paused_at.take() {
+            self.next_tick += paused_at.elapsed();
+        }
+    }
+
+    /// Returns `true` if [paused](Self::pause).
+    pub fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
     }
 
     /// Returns the [`MissedTickBehavior`] strategy currently being used.
@@ -118,10 +671,32 @@ impl Interval {
         self.period
     }
 
+    /// Returns the instant of the next scheduled tick.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep_util::interval_at;
+    /// # use std::time::{Duration, Instant};
+    ///
+    /// let start = Instant::now();
+    /// let mut i = interval_at(start, Duration::from_millis(20));
+    /// assert_eq!(i.next_tick(), start);
+    ///
+    /// i.tick();
+    /// assert_eq!(i.next_tick(), start + Duration::from_millis(20));
+    /// ```
+    pub fn next_tick(&self) -> Instant {
+        self.next_tick
+    }
+
     /// Sets a new period.
     ///
     /// Does not affect the existing scheduled next tick.
     ///
+    /// Resets the [cumulative drift](Self::cumulative_drift), as the tick grid has changed.
+    /// The [tick count](Self::tick_count) & [realized rate](Self::realized_rate) continue,
+    /// use [`Self::reset_stats`] to also clear these.
+    ///
     /// # Example
     /// ```
     /// use spin_sleep_util::interval;
@@ -135,6 +710,7 @@ impl Interval {
     pub fn set_period(&mut self, period: Duration) {
         assert!(period > Duration::ZERO, "`period` must be non-zero.");
         self.period = period;
+        self.cumulative_drift = Duration::ZERO;
     }
 
     /// Sets the [`MissedTickBehavior`] strategy that should be used.
@@ -168,6 +744,130 @@ impl Interval {
         self
     }
 
+    /// Returns whether the first tick fires at the start, see [`Self::with_fire_immediately`].
+    pub fn fire_immediately(&self) -> bool {
+        self.fire_immediately
+    }
+
+    /// Returns `Self` with the first tick firing at the start (`true`),
+    /// or one period after the start (`false`). Default `true`.
+    ///
+    /// ```text
+    /// with_fire_immediately(true)   tick    tick    tick
+    ///                               |-------|-------|----
+    ///                               start
+    ///
+    /// with_fire_immediately(false)          tick    tick
+    ///                               |-------|-------|----
+    ///                               start
+    /// ```
+    ///
+    /// This shifts the scheduled next tick so should be used before the first tick.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep_util::interval_at;
+    /// # use std::time::{Duration, Instant};
+    ///
+    /// let start = Instant::now();
+    /// let i = interval_at(start, Duration::from_millis(20)).with_fire_immediately(false);
+    /// assert_eq!(i.next_tick(), start + Duration::from_millis(20));
+    /// ```
+    pub fn with_fire_immediately(mut self, fire_immediately: bool) -> Self {
+        match (self.fire_immediately, fire_immediately) {
+            (true, false) => self.next_tick += self.period,
+            (false, true) => self.next_tick -= self.period,
+            _ => {}
+        }
+        self.fire_immediately = fire_immediately;
+        self
+    }
+
+    /// Returns the phase offset, see [`Self::with_phase_offset`].
+    pub fn phase_offset(&self) -> Duration {
+        self.phase_offset
+    }
+
+    /// Returns `Self` with every tick shifted `offset` earlier than the schedule grid,
+    /// e.g. to prepare 1ms before each vsync. Default zero.
+    ///
+    /// Shifted ticks in the past are skipped, so the next tick is the first shifted grid tick
+    /// at or after the current time.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep_util::interval_at;
+    /// # use std::time::{Duration, Instant};
+    ///
+    /// let vsync = Instant::now() + Duration::from_millis(10);
+    /// let i = interval_at(vsync, Duration::from_secs(1) / 60)
+    ///     .with_phase_offset(Duration::from_millis(1));
+    /// assert_eq!(i.next_tick(), vsync - Duration::from_millis(1));
+    /// ```
+    pub fn with_phase_offset(mut self, offset: Duration) -> Self {
+        let grid_tick = self.next_tick + self.phase_offset;
+        self.phase_offset = offset;
+        self.next_tick = grid_tick.checked_sub(offset).unwrap_or_else(|| {
+            // offset too large to represent, use the equivalent tick within a period
+            let period_ns = self.period.as_nanos();
+            grid_tick - Duration::from_nanos((offset.as_nanos() % period_ns) as u64)
+        });
+        self.skip_to(Instant::now());
+        self
+    }
+
+    /// Returns the max jitter, see [`Self::with_jitter`].
+    pub fn jitter(&self) -> Duration {
+        self.jitter
+    }
+
+    /// Returns `Self` waiting an additional uniformly random offset in `0..max` for each tick.
+    /// Default zero.
+    ///
+    /// Offsets are independent per tick & do not move the schedule grid, so the long-run
+    /// rate is unchanged. Useful to desynchronize many processes polling on the same
+    /// interval, avoiding a thundering herd on a shared resource.
+    ///
+    /// Applies to the waits of [`Self::tick`], [`Self::tick_detailed`], [`Self::tick_no_spin`]
+    /// & [`Self::tick_or`]. Returned tick instants are the un-jittered scheduled ticks.
+    /// Offsets are generated by a lightweight xorshift generator seeded once randomly.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # fn poll_shared_resource() {}
+    /// let mut interval = spin_sleep_util::interval(Duration::from_secs(1))
+    ///     .with_jitter(Duration::from_millis(100));
+    /// loop {
+    ///     interval.tick();
+    ///     poll_shared_resource();
+    /// }
+    /// ```
+    pub fn with_jitter(mut self, max: Duration) -> Self {
+        self.jitter = max;
+        if self.jitter_seed == 0 {
+            self.jitter_seed = RandomState::new().build_hasher().finish() | 1;
+        }
+        self
+    }
+
+    /// Returns the next random jitter offset in `0..jitter`, advancing the seed.
+    #[inline]
+    fn next_jitter(&mut self) -> Duration {
+        if self.jitter.is_zero() {
+            return Duration::ZERO;
+        }
+        // xorshift64
+        self.jitter_seed ^= self.jitter_seed << 13;
+        self.jitter_seed ^= self.jitter_seed >> 7;
+        self.jitter_seed ^= self.jitter_seed << 17;
+
+        let max_ns = u64::try_from(self.jitter.as_nanos()).unwrap_or(u64::MAX);
+        // map to 0..max_ns by multiply-shift, avoiding modulo bias
+        let offset = ((u128::from(self.jitter_seed) * u128::from(max_ns)) >> 64) as u64;
+        Duration::from_nanos(offset)
+    }
+
     /// Returns the configured [`SpinSleeper`].
     ///
     /// # Example
@@ -200,6 +900,14 @@ impl Interval {
         self.sleeper = sleeper;
     }
 
+    /// Returns `Self` with an embedded [`RateReporter`] using the given `report_period`.
+    ///
+    /// Rate reports are returned by [`Self::tick_and_report`].
+    pub fn with_rate_report(mut self, report_period: Duration) -> Self {
+        self.rate_reporter = Some(RateReporter::new(report_period));
+        self
+    }
+
     /// Returns `Self` with the specified [`SpinSleeper`].
     ///
     /// # Example
@@ -216,191 +924,100 @@ impl Interval {
         self.sleeper = sleeper;
         self
     }
-}
 
-/// Defines the behavior of an [`Interval`] when it misses a tick.
-///
-/// Generally, a tick is missed if too much time is spent without calling
-/// [`Interval::tick()`].
-///
-/// Default [`Skip`](MissedTickBehavior::Skip).
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
-pub enum MissedTickBehavior {
-    /// Ticks as fast as possible until caught up.
-    ///
-    /// When this strategy is used, [`Interval`] schedules ticks "normally" (the
-    /// same as it would have if the ticks hadn't been delayed), which results
-    /// in it firing ticks as fast as possible until it is caught up in time to
-    /// where it should be. Unlike [`Delay`] and [`Skip`], the ticks yielded
-    /// when `Burst` is used (the [`Instant`]s that [`tick`](Interval::tick)
-    /// yields) aren't different than they would have been if a tick had not
-    /// been missed. Like [`Skip`], and unlike [`Delay`], the ticks may be
-    /// shortened.
-    ///
-    /// This looks something like this:
-    /// ```text
-    /// Expected ticks: |     1     |     2     |     3     |     4     |     5     |     6     |
-    /// Actual ticks:   | work -----|          delay          | work | work | work -| work -----|
-    /// ```
+    /// Returns `Self` after [warming up](SpinSleeper::warm_up) the configured [`SpinSleeper`],
+    /// so the first tick that sleeps is as accurate as the rest.
     ///
-    /// In code:
+    /// Latency sensitive loops should use this, after any [`Self::with_spin_sleeper`], on the
+    /// thread that will tick. Not done by default to keep construction cheap.
     ///
-    /// ```no_run
-    /// use spin_sleep_util::{interval, MissedTickBehavior};
-    /// use std::time::Duration;
-    /// # fn task_that_takes_200_millis() {}
-    ///
-    /// let mut interval =
-    ///     interval(Duration::from_millis(50)).with_missed_tick_behavior(MissedTickBehavior::Burst);
-    ///
-    /// // First tick resolves immediately after creation
-    /// interval.tick();
-    ///
-    /// task_that_takes_200_millis();
-    /// // The `Interval` has missed a tick
-    ///
-    /// // Since we have exceeded our timeout, this will resolve immediately
-    /// interval.tick();
-    ///
-    /// // Since we are more than 100ms after the start of `interval`, this will
-    /// // also resolve immediately.
-    /// interval.tick();
-    ///
-    /// // Also resolves immediately, because it was supposed to resolve at
-    /// // 150ms after the start of `interval`
-    /// interval.tick();
-    ///
-    /// // Resolves immediately
-    /// interval.tick();
-    ///
-    /// // Since we have gotten to 200ms after the start of `interval`, this
-    /// // will resolve after 50ms
-    /// interval.tick();
+    /// # Example
     /// ```
+    /// use spin_sleep_util::interval;
+    /// # use std::time::Duration;
     ///
-    /// [`Delay`]: MissedTickBehavior::Delay
-    /// [`Skip`]: MissedTickBehavior::Skip
-    Burst,
-
-    /// Tick at multiples of `period` from when [`tick`] was called, rather than
-    /// from `start`.
-    ///
-    /// When this strategy is used and [`Interval`] has missed a tick, instead
-    /// of scheduling ticks to fire at multiples of `period` from `start` (the
-    /// time when the first tick was fired), it schedules all future ticks to
-    /// happen at a regular `period` from the point when [`tick`] was called.
-    /// Unlike [`Burst`] and [`Skip`], ticks are not shortened, and they aren't
-    /// guaranteed to happen at a multiple of `period` from `start` any longer.
-    ///
-    /// This looks something like this:
-    /// ```text
-    /// Expected ticks: |     1     |     2     |     3     |     4     |     5     |     6     |
-    /// Actual ticks:   | work -----|          delay          | work -----| work -----| work -----|
+    /// let mut i = interval(Duration::from_millis(5)).with_warm_up();
+    /// i.tick();
     /// ```
+    pub fn with_warm_up(self) -> Self {
+        self.sleeper.warm_up();
+        self
+    }
+}
+
+/// Outcome of [`Interval::tick_detailed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TickOutcome {
+    /// The tick was in the future, so slept until the scheduled tick time.
+    OnTime {
+        /// Scheduled tick time.
+        scheduled: Instant,
+        /// Duration slept until the scheduled tick time.
+        slept: Duration,
+    },
+    /// The tick was in the past, so returned without sleeping.
+    /// The next tick is scheduled according to the [`MissedTickBehavior`].
+    Missed {
+        /// Scheduled tick time.
+        scheduled: Instant,
+        /// Whole periods between the scheduled tick and the current time that will not be ticked.
+        /// Always zero for [`MissedTickBehavior::Burst`].
+        skipped: u64,
+    },
+}
+
+impl TickOutcome {
+    /// Returns the scheduled tick time.
+    pub fn scheduled(self) -> Instant {
+        match self {
+            Self::OnTime { scheduled, .. } | Self::Missed { scheduled, .. } => scheduled,
+        }
+    }
+}
+
+impl IntoIterator for Interval {
+    type Item = Instant;
+    type IntoIter = TickIter;
+
+    /// Converts into an endless iterator of ticks, see [`TickIter`].
     ///
-    /// In code:
-    ///
-    /// ```no_run
-    /// use spin_sleep_util::{interval, MissedTickBehavior};
-    /// use std::time::Duration;
-    /// # fn task_that_takes_more_than_50_millis() {}
-    ///
-    /// let mut interval =
-    ///     interval(Duration::from_millis(50)).with_missed_tick_behavior(MissedTickBehavior::Delay);
-    ///
-    /// task_that_takes_more_than_50_millis();
-    /// // The `Interval` has missed a tick
-    ///
-    /// // Since we have exceeded our timeout, this will resolve immediately
-    /// interval.tick();
-    ///
-    /// // But this one, rather than also resolving immediately, as might happen
-    /// // with the `Burst` or `Skip` behaviors, will not resolve until
-    /// // 50ms after the call to `tick` up above. That is, in `tick`, when we
-    /// // recognize that we missed a tick, we schedule the next tick to happen
-    /// // 50ms (or whatever the `period` is) from right then, not from when
-    /// // were *supposed* to tick
-    /// interval.tick();
-    /// ```
-    ///
-    /// [`Burst`]: MissedTickBehavior::Burst
-    /// [`Skip`]: MissedTickBehavior::Skip
-    /// [`tick`]: Interval::tick
-    Delay,
-
-    /// Skips missed ticks and tick on the next multiple of `period` from
-    /// `start`.
-    ///
-    /// When this strategy is used, [`Interval`] schedules the next tick to fire
-    /// at the next-closest tick that is a multiple of `period` away from
-    /// `start` (the point where [`Interval`] first ticked). Like [`Burst`], all
-    /// ticks remain multiples of `period` away from `start`, but unlike
-    /// [`Burst`], the ticks may not be *one* multiple of `period` away from the
-    /// last tick. Like [`Delay`], the ticks are no longer the same as they
-    /// would have been if ticks had not been missed, but unlike [`Delay`], and
-    /// like [`Burst`], the ticks may be shortened to be less than one `period`
-    /// away from each other.
-    ///
-    /// This looks something like this:
-    /// ```text
-    /// Expected ticks: |     1     |     2     |     3     |     4     |     5     |     6     |
-    /// Actual ticks:   | work -----|          delay          | work ---| work -----| work -----|
+    /// # Example
     /// ```
-    ///
-    /// In code:
-    ///
-    /// ```no_run
-    /// use spin_sleep_util::{interval, MissedTickBehavior};
-    /// use std::time::Duration;
-    /// # fn task_that_takes_75_millis() {}
-    ///
-    /// let mut interval = interval(Duration::from_millis(50));
-    ///
-    /// task_that_takes_75_millis();
-    /// // The `Interval` has missed a tick
-    ///
-    /// // Since we have exceeded our timeout, this will resolve immediately
-    /// interval.tick();
-    ///
-    /// // This one will resolve after 25ms, 100ms after the start of
-    /// // `interval`, which is the closest multiple of `period` from the start
-    /// // of `interval` after the call to `tick` up above.
-    /// interval.tick();
+    /// # use std::time::Duration;
+    /// // run exactly 3 ticks at 1kHz
+    /// for tick in spin_sleep_util::interval(Duration::from_millis(1)).into_iter().take(3) {
+    ///     // do work
+    /// #   let _ = tick;
+    /// }
     /// ```
-    ///
-    /// [`Burst`]: MissedTickBehavior::Burst
-    /// [`Delay`]: MissedTickBehavior::Delay
-    #[default]
-    Skip,
+    fn into_iter(self) -> Self::IntoIter {
+        TickIter(self)
+    }
 }
 
-impl MissedTickBehavior {
-    /// If a tick is missed, this method is called to determine when the next tick should happen.
-    fn next_tick(&self, missed_tick: Instant, now: Instant, period: Duration) -> Instant {
-        match self {
-            Self::Burst => missed_tick + period,
-            Self::Delay => now + period,
-            Self::Skip => {
-                now + period
-                    - Duration::from_nanos(
-                        ((now - missed_tick).as_nanos() % period.as_nanos())
-                            .try_into()
-                            // This operation is practically guaranteed not to
-                            // fail, as in order for it to fail, `period` would
-                            // have to be longer than `now - timeout`, and both
-                            // would have to be longer than 584 years.
-                            //
-                            // If it did fail, there's not a good way to pass
-                            // the error along to the user, so we just panic.
-                            .expect(
-                                "too much time has elapsed since the interval was supposed to tick",
-                            ),
-                    )
-            }
-        }
+/// Endless iterator yielding [`Interval::tick`] instants, sleeping until each tick.
+///
+/// Created using [`Interval::into_iter`]. Use adapters like [`Iterator::take`] to bound it.
+#[derive(Debug)]
+pub struct TickIter(Interval);
+
+impl TickIter {
+    /// Returns the inner [`Interval`].
+    pub fn into_inner(self) -> Interval {
+        self.0
     }
 }
 
+impl Iterator for TickIter {
+    type Item = Instant;
+
+    fn next(&mut self) -> Option<Instant> {
+        Some(self.0.tick())
+    }
+}
+
+impl std::iter::FusedIterator for TickIter {}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -416,4 +1033,324 @@ mod test {
     fn zero_period() {
         interval(Duration::ZERO);
     }
+
+    #[test]
+    fn try_zero_period() {
+        assert_eq!(
+            try_interval_at(Instant::now(), Duration::ZERO).err(),
+            Some(IntervalError::ZeroPeriod)
+        );
+    }
+
+    #[test]
+    fn skip_to_grid() {
+        let start = Instant::now();
+        let period = Duration::from_millis(10);
+        let mut i = interval_at(start, period);
+
+        i.skip_to(start + period * 2);
+        assert_eq!(i.next_tick(), start + period * 2);
+
+        i.skip_to(start + period);
+        assert_eq!(i.next_tick(), start + period * 2);
+
+        i.skip_to(start + period * 2 + Duration::from_nanos(1));
+        assert_eq!(i.next_tick(), start + period * 3);
+    }
+
+    #[test]
+    fn tick_detailed_on_time() {
+        let start = Instant::now() + Duration::from_millis(2);
+        let mut i = interval_at(start, Duration::from_millis(10));
+        match i.tick_detailed() {
+            TickOutcome::OnTime { scheduled, slept } => {
+                assert_eq!(scheduled, start);
+                assert!(slept <= Duration::from_millis(2));
+            }
+            outcome => panic!("unexpected {outcome:?}"),
+        }
+    }
+
+    #[test]
+    fn tick_detailed_missed() {
+        let period = Duration::from_millis(100);
+        let start = Instant::now() - Duration::from_millis(350);
+
+        let mut i = interval_at(start, period);
+        assert_eq!(
+            i.tick_detailed(),
+            TickOutcome::Missed {
+                scheduled: start,
+                skipped: 3
+            }
+        );
+
+        let mut i = interval_at(start, period).with_missed_tick_behavior(MissedTickBehavior::Burst);
+        assert_eq!(
+            i.tick_detailed(),
+            TickOutcome::Missed {
+                scheduled: start,
+                skipped: 0
+            }
+        );
+    }
+
+    #[test]
+    fn tick_with_lateness() {
+        let period = Duration::from_millis(100);
+        let start = Instant::now() - Duration::from_millis(50);
+        let mut i = interval_at(start, period);
+
+        let (tick, lateness) = i.tick_with_lateness();
+        assert_eq!(tick, start);
+        assert!(lateness >= Duration::from_millis(50), "{lateness:?}");
+
+        let (tick, lateness) = i.tick_with_lateness();
+        assert_eq!(tick, start + period);
+        assert!(lateness < Duration::from_millis(50), "{lateness:?}");
+    }
+
+    #[test]
+    fn reset_immediately() {
+        let period = Duration::from_secs(10);
+        let mut i = interval_at(Instant::now() + period, period);
+
+        let before = Instant::now();
+        i.reset_immediately();
+        let tick = i.tick();
+        assert!(tick >= before);
+        assert!(before.elapsed() < Duration::from_secs(5));
+        assert_eq!(i.next_tick(), tick + period);
+        assert_eq!(i.period(), period);
+    }
+
+    #[test]
+    fn poll_tick() {
+        let period = Duration::from_millis(100);
+        let start = Instant::now() + period;
+        let mut i = interval_at(start, period);
+        assert_eq!(i.poll_tick(), None);
+        assert_eq!(i.next_tick(), start);
+        assert_eq!(i.tick_count(), 0);
+
+        let start = Instant::now() - Duration::from_millis(250);
+        let mut i = interval_at(start, period);
+        assert_eq!(i.poll_tick(), Some(start));
+        assert_eq!(i.next_tick(), start + period * 3);
+        assert_eq!(i.missed_ticks(), 2);
+        assert_eq!(i.poll_tick(), None);
+        assert_eq!(i.tick_count(), 1);
+    }
+
+    #[test]
+    fn poll_tick_slightly_late() {
+        let period = Duration::from_millis(20);
+        let start = Instant::now();
+        let mut i = interval_at(start, period).with_missed_tick_behavior(MissedTickBehavior::Delay);
+        for n in 0..4 {
+            spin_sleep::sleep_until(i.next_tick() + Duration::from_millis(2));
+            assert_eq!(i.poll_tick(), Some(start + period * n));
+        }
+        assert_eq!(i.missed_ticks(), 0);
+        assert_eq!(i.next_tick(), start + period * 4);
+    }
+
+    #[test]
+    fn missed_ticks() {
+        let period = Duration::from_millis(100);
+        let start = Instant::now() - Duration::from_millis(350);
+
+        let mut i = interval_at(start, period);
+        assert_eq!(i.missed_ticks(), 0);
+        i.tick();
+        // 3 skipped, the late tick is still returned
+        assert_eq!(i.missed_ticks(), 3);
+        i.reset_missed_ticks();
+        assert_eq!(i.missed_ticks(), 0);
+
+        let mut i = interval_at(start, period).with_missed_tick_behavior(MissedTickBehavior::Delay);
+        i.tick();
+        assert_eq!(i.missed_ticks(), 1);
+
+        // 4 due, 2 returned as steps
+        let mut i = interval_at(start, period);
+        assert_eq!(i.tick_steps(2), 2);
+        assert_eq!(i.missed_ticks(), 2);
+
+        let mut i = interval_at(start, period);
+        assert_eq!(i.tick_steps(4), 4);
+        assert_eq!(i.missed_ticks(), 0);
+    }
+
+    #[test]
+    fn cumulative_drift_missed() {
+        let period = Duration::from_millis(100);
+        let start = Instant::now() - Duration::from_millis(30);
+        let mut i = interval_at(start, period);
+        i.tick();
+        assert!(i.cumulative_drift() >= Duration::from_millis(30));
+
+        i.reset_cumulative_drift();
+        assert_eq!(i.cumulative_drift(), Duration::ZERO);
+    }
+
+    #[test]
+    fn tick_steps() {
+        let period = Duration::from_millis(100);
+        let start = Instant::now() - Duration::from_millis(350);
+
+        let mut i = interval_at(start, period);
+        assert_eq!(i.tick_steps(10), 4);
+        assert_eq!(i.next_tick(), start + period * 4);
+
+        let mut i = interval_at(start, period);
+        assert_eq!(i.tick_steps(2), 2);
+        assert_eq!(i.next_tick(), start + period * 4);
+
+        let mut i = interval_at(start, period);
+        assert_eq!(i.tick_steps(0), 0);
+        assert_eq!(i.next_tick(), start);
+        assert_eq!(i.missed_ticks(), 0);
+
+        // more missed periods than fit in a u32
+        let period = Duration::from_nanos(1);
+        let start = Instant::now() - Duration::from_secs(5);
+        let mut i = interval_at(start, period);
+        assert_eq!(i.tick_steps(3), 3);
+        assert!(i.missed_ticks() > u64::from(u32::MAX));
+        assert!(i.next_tick() > start + Duration::from_secs(5));
+
+        let start = Instant::now() + Duration::from_millis(1);
+        let mut i = interval_at(start, period);
+        assert_eq!(i.tick_steps(10), 1);
+        assert!(Instant::now() >= start);
+    }
+
+    #[test]
+    fn fire_immediately_toggle() {
+        let start = Instant::now();
+        let period = Duration::from_millis(10);
+        let i = interval_at(start, period).with_fire_immediately(false);
+        assert!(!i.fire_immediately());
+        assert_eq!(i.next_tick(), start + period);
+
+        let i = i.with_fire_immediately(false).with_fire_immediately(true);
+        assert!(i.fire_immediately());
+        assert_eq!(i.next_tick(), start);
+    }
+
+    #[test]
+    fn tick_iter_take() {
+        let start = Instant::now();
+        let period = Duration::from_millis(2);
+        let ticks: Vec<_> = interval_at(start, period).into_iter().take(3).collect();
+        assert_eq!(ticks, [start, start + period, start + period * 2]);
+    }
+
+    #[test]
+    fn tick_or() {
+        let period = Duration::from_millis(2);
+        let mut interval = interval(period);
+        let cancel = AtomicBool::new(false);
+
+        let tick = interval.tick_or(&cancel).unwrap();
+        assert!(Instant::now() >= tick);
+        assert_eq!(interval.next_tick, tick + period);
+
+        cancel.store(true, Ordering::Release);
+        assert_eq!(interval.tick_or(&cancel), None);
+        // cancelled tick is not consumed
+        assert_eq!(interval.next_tick, tick + period);
+    }
+
+    #[test]
+    fn collect_ticks_until_capped() {
+        let start = Instant::now();
+        let period = Duration::from_nanos(1);
+        let mut interval = interval_at(start, period);
+
+        let ticks = interval.collect_ticks_until(start + Duration::from_secs(1));
+        assert_eq!(ticks.len(), MAX_COLLECTED_TICKS);
+        assert_eq!(
+            ticks.last(),
+            Some(&(start + period * (MAX_COLLECTED_TICKS as u32 - 1)))
+        );
+        // continues from the first uncollected tick
+        assert_eq!(
+            interval.next_tick(),
+            start + period * MAX_COLLECTED_TICKS as u32
+        );
+
+        assert!(interval.collect_ticks_until(start).is_empty());
+    }
+
+    #[test]
+    fn jitter() {
+        let period = Duration::from_millis(10);
+        let max = Duration::from_millis(4);
+        let mut i = interval(period).with_jitter(max);
+        assert_eq!(i.jitter(), max);
+
+        let mut offsets = Vec::new();
+        for _ in 0..100 {
+            let offset = i.next_jitter();
+            assert!(offset < max, "{offset:?}");
+            offsets.push(offset);
+        }
+        offsets.dedup();
+        assert!(offsets.len() > 50, "{offsets:?}");
+
+        // schedule grid is unaffected
+        let start = i.tick();
+        let tick = i.tick();
+        assert_eq!(tick, start + period);
+        assert_eq!(i.next_tick(), start + period * 2);
+    }
+
+    #[test]
+    fn phase_offset() {
+        let start = Instant::now() + Duration::from_secs(1);
+        let period = Duration::from_millis(20);
+        let offset = Duration::from_millis(3);
+
+        let i = interval_at(start, period).with_phase_offset(offset);
+        assert_eq!(i.phase_offset(), offset);
+        assert_eq!(i.next_tick(), start - offset);
+
+        // changing the offset applies relative to the original grid
+        let i = i.with_phase_offset(Duration::from_millis(5));
+        assert_eq!(i.next_tick(), start - Duration::from_millis(5));
+
+        // shifted tick in the past advances along the shifted grid
+        let start = Instant::now();
+        let i = interval_at(start, period).with_phase_offset(offset);
+        assert_eq!(i.next_tick(), start + period - offset);
+    }
+
+    #[test]
+    fn set_period_stats() {
+        let mut i = interval(Duration::from_millis(1));
+        i.tick();
+        i.tick();
+        i.cumulative_drift = Duration::from_micros(50);
+
+        i.set_period(Duration::from_millis(2));
+        assert_eq!(i.cumulative_drift(), Duration::ZERO);
+        assert_eq!(i.tick_count(), 2);
+
+        i.reset_stats();
+        assert_eq!(i.tick_count(), 0);
+    }
+
+    #[test]
+    fn pause_resume() {
+        let start = Instant::now();
+        let mut i = interval_at(start, Duration::from_millis(10));
+        i.pause();
+        assert!(i.is_paused());
+        std::thread::sleep(Duration::from_millis(5));
+        i.resume();
+        assert!(!i.is_paused());
+        assert!(i.next_tick() >= start + Duration::from_millis(5));
+    }
 }
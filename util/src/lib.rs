@@ -17,8 +17,14 @@
 //! }
 //! ```
 
+#[cfg(feature = "tokio")]
+mod async_interval;
 mod interval;
+mod limiter;
 mod report;
 
+#[cfg(feature = "tokio")]
+pub use async_interval::*;
 pub use interval::*;
+pub use limiter::*;
 pub use report::*;
@@ -0,0 +1,159 @@
+use spin_sleep::SpinSleeper;
+use std::time::{Duration, Instant};
+
+/// Token bucket rate limiter allowing at most `rate` operations per second on average,
+/// with bursts of up to `burst` operations.
+///
+/// [`RateLimiter::acquire`] uses a [`SpinSleeper`] to precisely wait until a token is available.
+///
+/// # Example
+/// ```no_run
+/// use spin_sleep_util::RateLimiter;
+/// # fn send_request() {}
+///
+/// // at most 100 requests per second, allowing bursts of 10
+/// let mut limiter = RateLimiter::new(100.0, 10);
+/// loop {
+///     limiter.acquire();
+///     send_request();
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    /// Duration to refill a single token.
+    token_period: Duration,
+    /// Duration to refill a full bucket less one token.
+    burst_tolerance: Duration,
+    /// Time at which the bucket will be full, if in the future.
+    /// `None` if it will never refill, i.e. the time is unrepresentable.
+    full_at: Option<Instant>,
+    sleeper: SpinSleeper,
+}
+
+impl RateLimiter {
+    /// Returns a new, full, [`RateLimiter`] refilling `rate` tokens per second up to a
+    /// max of `burst` tokens.
+    ///
+    /// A `rate` so low that the refill time cannot be represented as an [`Instant`]
+    /// will never refill.
+    ///
+    /// # Panics
+    /// Panics if `rate` is not positive & finite, or `burst` is zero.
+    #[track_caller]
+    pub fn new(rate: f64, burst: u32) -> Self {
+        assert!(
+            rate > 0.0 && rate.is_finite(),
+            "`rate` must be positive & finite."
+        );
+        assert!(burst > 0, "`burst` must be non-zero.");
+
+        let token_period = Duration::try_from_secs_f64(rate.recip()).unwrap_or(Duration::MAX);
+        Self {
+            token_period,
+            burst_tolerance: token_period.checked_mul(burst - 1).unwrap_or(Duration::MAX),
+            full_at: Some(Instant::now()),
+            sleeper: SpinSleeper::default(),
+        }
+    }
+
+    /// Returns `Self` with the specified [`SpinSleeper`].
+    pub fn with_spin_sleeper(mut self, sleeper: SpinSleeper) -> Self {
+        self.sleeper = sleeper;
+        self
+    }
+
+    /// Returns the configured [`SpinSleeper`].
+    pub fn spin_sleeper(&self) -> SpinSleeper {
+        self.sleeper
+    }
+
+    /// Takes a token, sleeping until one is available if necessary.
+    ///
+    /// If the limiter will never refill, see [`RateLimiter::new`], this blocks forever.
+    pub fn acquire(&mut self) {
+        let now = Instant::now();
+        let Some(available_at) = self.available_at() else {
+            loop {
+                self.sleeper.sleep(Duration::MAX);
+            }
+        };
+        if now < available_at {
+            self.sleeper.sleep_until_with_now(available_at, now);
+        }
+        self.take(now.max(available_at));
+    }
+
+    /// Takes a token if one is available without sleeping, returning `true` if taken.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep_util::RateLimiter;
+    ///
+    /// let mut limiter = RateLimiter::new(1.0, 2);
+    /// assert!(limiter.try_acquire());
+    /// assert!(limiter.try_acquire());
+    /// assert!(!limiter.try_acquire());
+    /// ```
+    pub fn try_acquire(&mut self) -> bool {
+        let now = Instant::now();
+        match self.available_at() {
+            Some(available_at) if now >= available_at => {}
+            _ => return false,
+        }
+        self.take(now);
+        true
+    }
+
+    /// Returns the time the next token is available, which may be in the past.
+    /// `None` if no more tokens will become available.
+    fn available_at(&self) -> Option<Instant> {
+        let full_at = self.full_at?;
+        Some(
+            full_at
+                .checked_sub(self.burst_tolerance)
+                .unwrap_or(full_at.min(Instant::now())),
+        )
+    }
+
+    /// Takes a token at `now`.
+    fn take(&mut self, now: Instant) {
+        self.full_at = self
+            .full_at
+            .and_then(|full_at| full_at.max(now).checked_add(self.token_period));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn burst_then_rate() {
+        let mut limiter = RateLimiter::new(500.0, 3);
+        let start = Instant::now();
+        for _ in 0..3 {
+            limiter.acquire();
+        }
+        assert!(start.elapsed() < Duration::from_millis(2));
+
+        for _ in 0..3 {
+            limiter.acquire();
+        }
+        // 3 more tokens at 2ms each
+        assert!(start.elapsed() >= Duration::from_millis(6));
+        assert!(!limiter.try_acquire());
+    }
+
+    #[test]
+    fn tiny_rate() {
+        let mut limiter = RateLimiter::new(1e-30, 1);
+        assert!(limiter.try_acquire());
+        assert!(!limiter.try_acquire());
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_burst() {
+        RateLimiter::new(1.0, 0);
+    }
+}
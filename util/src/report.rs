@@ -1,4 +1,5 @@
-use std::time::{Duration, Instant};
+use spin_sleep::{Clock, Nanoseconds, StdClock};
+use std::{collections::VecDeque, time::Duration};
 
 /// Helper for regularly reporting a rate per second, like fps.
 ///
@@ -17,11 +18,60 @@ use std::time::{Duration, Instant};
 ///     }
 /// }
 /// ```
+///
+/// Time is measured using the [`Clock`] `C`, see [`RateReporter::with_clock`].
 #[derive(Debug)]
-pub struct RateReporter {
-    report_period: Duration,
-    start: Instant,
+pub struct RateReporter<C = StdClock> {
+    trigger: ReportTrigger,
+    /// `clock` time of the report start.
+    start: Nanoseconds,
     rate_count: u32,
+    clock: C,
+    intervals: Option<IntervalBuffer>,
+    jitter: Option<JitterStats>,
+}
+
+/// Ring buffer of recent inter-increment intervals, see [`RateReporter::with_capacity`].
+#[derive(Debug, Clone)]
+struct IntervalBuffer {
+    capacity: usize,
+    intervals: VecDeque<Duration>,
+    /// `clock` time of the last increment.
+    last_increment: Option<Nanoseconds>,
+}
+
+/// Running inter-increment interval mean & variance, see [`RateReporter::with_jitter`].
+#[derive(Debug, Clone, Copy, Default)]
+struct JitterStats {
+    /// `clock` time of the last increment.
+    last_increment: Option<Nanoseconds>,
+    count: u32,
+    /// Mean interval in seconds.
+    mean: f64,
+    /// Sum of squared differences from the mean, per Welford's algorithm.
+    m2: f64,
+}
+
+/// Interval percentiles returned by [`RateReporter::report_percentiles`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Percentiles {
+    /// Mean rate per second, as returned by [`RateReporter::report`].
+    pub rate: f64,
+    /// Median inter-increment interval.
+    pub p50: Duration,
+    /// 95th percentile inter-increment interval.
+    pub p95: Duration,
+    /// 99th percentile inter-increment interval.
+    pub p99: Duration,
+}
+
+/// When a [`RateReporter`] report is due.
+#[derive(Debug, Clone, Copy)]
+enum ReportTrigger {
+    /// At least this duration has elapsed.
+    Period(Duration),
+    /// At least this many increments have been counted.
+    Count(u32),
 }
 
 impl RateReporter {
@@ -29,25 +79,210 @@ impl RateReporter {
     /// duration to return reports and aggregate inside.
     pub fn new(report_period: Duration) -> Self {
         Self {
-            report_period,
-            start: Instant::now(),
+            trigger: ReportTrigger::Period(report_period),
+            start: StdClock.now(),
             rate_count: 0,
+            clock: StdClock,
+            intervals: None,
+            jitter: None,
         }
     }
 
+    /// Returns a new [`RateReporter`] that returns reports every `count` increments,
+    /// rather than by time. Reports are the mean rate per second since the last report.
+    ///
+    /// Useful when event cadence is irregular and equal-sample reports are preferred
+    /// over equal-time reports.
+    ///
+    /// # Panics
+    /// Panics if `count` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// let mut reporter = spin_sleep_util::RateReporter::new_every(3);
+    /// assert!(reporter.increment_and_report().is_none());
+    /// assert!(reporter.increment_and_report().is_none());
+    /// assert!(reporter.increment_and_report().is_some());
+    /// ```
+    #[track_caller]
+    pub fn new_every(count: u32) -> Self {
+        assert!(count > 0, "`count` must be non-zero.");
+        Self {
+            trigger: ReportTrigger::Count(count),
+            start: StdClock.now(),
+            rate_count: 0,
+            clock: StdClock,
+            intervals: None,
+            jitter: None,
+        }
+    }
+}
+
+impl<C: Clock> RateReporter<C> {
+    /// Returns the reporter using the given [`Clock`], e.g. a mock clock for
+    /// deterministic tests. The rate count & report start are reset.
+    ///
+    /// # Example
+    /// ```
+    /// use spin_sleep::{Clock, Nanoseconds};
+    /// use std::{cell::Cell, time::Duration};
+    ///
+    /// struct MockClock(Cell<Nanoseconds>);
+    ///
+    /// impl Clock for MockClock {
+    ///     fn now(&self) -> Nanoseconds {
+    ///         self.0.get()
+    ///     }
+    /// }
+    ///
+    /// let clock = MockClock(Cell::new(0));
+    /// let mut reporter =
+    ///     spin_sleep_util::RateReporter::new(Duration::from_secs(1)).with_clock(&clock);
+    ///
+    /// reporter.increment();
+    /// assert_eq!(reporter.report(), None);
+    ///
+    /// clock.0.set(2_000_000_000);
+    /// assert_eq!(reporter.report(), Some(0.5));
+    /// ```
+    pub fn with_clock<C2: Clock>(self, clock: C2) -> RateReporter<C2> {
+        RateReporter {
+            trigger: self.trigger,
+            start: clock.now(),
+            rate_count: 0,
+            clock,
+            intervals: self.intervals.map(|b| IntervalBuffer::new(b.capacity)),
+            jitter: self.jitter.map(|_| JitterStats::default()),
+        }
+    }
+
+    /// Returns the reporter tracking the standard deviation of inter-increment intervals
+    /// for [`Self::report_with_jitter`]. Allocation free.
+    pub fn with_jitter(mut self) -> Self {
+        self.jitter = Some(JitterStats::default());
+        self
+    }
+
+    /// Returns the reporter recording up to `capacity` of the most recent inter-increment
+    /// intervals for [`Self::report_percentiles`].
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero.
+    #[track_caller]
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        assert!(capacity > 0, "`capacity` must be non-zero.");
+        self.intervals = Some(IntervalBuffer::new(capacity));
+        self
+    }
+
     /// Increment the rate count for the next report.
     pub fn increment(&mut self) {
         self.rate_count = self.rate_count.saturating_add(1);
+        if self.intervals.is_none() && self.jitter.is_none() {
+            return;
+        }
+        let now = self.clock.now();
+        if let Some(buffer) = &mut self.intervals {
+            buffer.record(now);
+        }
+        if let Some(jitter) = &mut self.jitter {
+            jitter.record(now);
+        }
+    }
+
+    /// Like [`Self::report`] also returning the standard deviation of the inter-increment
+    /// intervals, i.e. frame time jitter, then resetting it. See [`Self::with_jitter`].
+    ///
+    /// Returns `None`, without reporting, if no intervals have been recorded.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # fn render() {}
+    /// let mut reporter = spin_sleep_util::RateReporter::new(Duration::from_secs(1)).with_jitter();
+    /// loop {
+    ///     render();
+    ///     reporter.increment();
+    ///     if let Some((fps, jitter)) = reporter.report_with_jitter() {
+    ///         println!("{fps:.0}fps ±{jitter:?}");
+    ///     }
+    /// }
+    /// ```
+    pub fn report_with_jitter(&mut self) -> Option<(f64, Duration)> {
+        if self.jitter?.count == 0 {
+            return None;
+        }
+        let rate = self.report()?;
+        let jitter = self.jitter.as_mut()?;
+        let stddev = (jitter.m2 / f64::from(jitter.count)).sqrt();
+        jitter.reset();
+        Some((rate, Duration::from_secs_f64(stddev)))
+    }
+
+    /// Like [`Self::report`] also returning percentiles of the recorded inter-increment
+    /// intervals, then clearing them. See [`Self::with_capacity`].
+    ///
+    /// Returns `None`, without reporting, if no intervals have been recorded.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # fn handle_request() {}
+    /// let mut reporter =
+    ///     spin_sleep_util::RateReporter::new(Duration::from_secs(1)).with_capacity(1024);
+    /// loop {
+    ///     handle_request();
+    ///     reporter.increment();
+    ///     if let Some(p) = reporter.report_percentiles() {
+    ///         println!("{:.0}/s p50: {:?}, p99: {:?}", p.rate, p.p50, p.p99);
+    ///     }
+    /// }
+    /// ```
+    pub fn report_percentiles(&mut self) -> Option<Percentiles> {
+        if self.intervals.as_ref()?.intervals.is_empty() {
+            return None;
+        }
+        let rate = self.report()?;
+        let buffer = self.intervals.as_mut()?;
+
+        let sorted = buffer.intervals.make_contiguous();
+        sorted.sort_unstable();
+        let percentile = |p: usize| sorted[(sorted.len() * p).div_ceil(100).max(1) - 1];
+        let percentiles = Percentiles {
+            rate,
+            p50: percentile(50),
+            p95: percentile(95),
+            p99: percentile(99),
+        };
+        buffer.intervals.clear();
+        Some(percentiles)
     }
 
     /// If at least `report_period` has elapsed since the last report returns the mean rate per second
     /// and resets the rate count to zero and start to now. Otherwise returns `None`.
+    ///
+    /// If constructed with [`Self::new_every`] reports once the rate count reaches the configured
+    /// count instead.
+    ///
+    /// Always returns `None` while no time has elapsed, e.g. with a coarse clock, as the rate
+    /// would be infinite.
     pub fn report(&mut self) -> Option<f64> {
-        let now = Instant::now();
-        let elapsed = now.duration_since(self.start);
-        if elapsed < self.report_period {
+        if let ReportTrigger::Count(count) = self.trigger {
+            if self.rate_count < count {
+                return None;
+            }
+        }
+
+        let now = self.clock.now();
+        let elapsed = Duration::from_nanos(now.saturating_sub(self.start));
+        if elapsed.is_zero() {
             return None;
         }
+        if let ReportTrigger::Period(report_period) = self.trigger {
+            if elapsed < report_period {
+                return None;
+            }
+        }
 
         let report = f64::from(self.rate_count) / elapsed.as_secs_f64();
         self.rate_count = 0;
@@ -62,8 +297,206 @@ impl RateReporter {
     }
 
     /// Reset rate count to zero & report start to now.
+    ///
+    /// Also clears any recorded [intervals](Self::with_capacity) & [jitter](Self::with_jitter).
     pub fn reset(&mut self) {
         self.rate_count = 0;
-        self.start = Instant::now();
+        self.start = self.clock.now();
+        if let Some(buffer) = &mut self.intervals {
+            buffer.intervals.clear();
+            buffer.last_increment = None;
+        }
+        if let Some(jitter) = &mut self.jitter {
+            *jitter = JitterStats::default();
+        }
+    }
+}
+
+impl IntervalBuffer {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            intervals: VecDeque::with_capacity(capacity),
+            last_increment: None,
+        }
+    }
+
+    fn record(&mut self, now: Nanoseconds) {
+        if let Some(last) = self.last_increment.replace(now) {
+            if self.intervals.len() == self.capacity {
+                self.intervals.pop_front();
+            }
+            self.intervals
+                .push_back(Duration::from_nanos(now.saturating_sub(last)));
+        }
+    }
+}
+
+impl JitterStats {
+    fn record(&mut self, now: Nanoseconds) {
+        if let Some(last) = self.last_increment.replace(now) {
+            let interval = Duration::from_nanos(now.saturating_sub(last)).as_secs_f64();
+            self.count = self.count.saturating_add(1);
+            let delta = interval - self.mean;
+            self.mean += delta / f64::from(self.count);
+            self.m2 += delta * (interval - self.mean);
+        }
+    }
+
+    /// Resets the accumulated stats, keeping the last increment time.
+    fn reset(&mut self) {
+        *self = Self {
+            last_increment: self.last_increment,
+            ..<_>::default()
+        };
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn every_zero() {
+        RateReporter::new_every(0);
+    }
+
+    /// Mock clock advanced manually.
+    #[derive(Debug, Default)]
+    struct MockClock(std::cell::Cell<Nanoseconds>);
+
+    impl Clock for MockClock {
+        fn now(&self) -> Nanoseconds {
+            self.0.get()
+        }
+    }
+
+    #[test]
+    fn period_gating() {
+        let clock = MockClock::default();
+        let mut reporter = RateReporter::new(Duration::from_millis(100)).with_clock(&clock);
+
+        for _ in 0..9 {
+            clock.0.set(clock.0.get() + 10_000_000);
+            assert_eq!(reporter.increment_and_report(), None);
+        }
+        clock.0.set(clock.0.get() + 10_000_000);
+        assert_eq!(reporter.increment_and_report(), Some(100.0));
+
+        // new report period starts
+        clock.0.set(clock.0.get() + 99_999_999);
+        assert_eq!(reporter.increment_and_report(), None);
+    }
+
+    #[test]
+    fn percentiles() {
+        let clock = MockClock::default();
+        let mut reporter = RateReporter::new(Duration::from_millis(100))
+            .with_capacity(100)
+            .with_clock(&clock);
+        assert_eq!(reporter.report_percentiles(), None);
+
+        reporter.increment();
+        for ms in 1..=100 {
+            clock.0.set(clock.0.get() + ms * 1_000_000);
+            reporter.increment();
+        }
+        let p = reporter.report_percentiles().unwrap();
+        assert_eq!(p.p50, Duration::from_millis(50));
+        assert_eq!(p.p95, Duration::from_millis(95));
+        assert_eq!(p.p99, Duration::from_millis(99));
+
+        // cleared after report
+        assert_eq!(reporter.report_percentiles(), None);
+    }
+
+    #[test]
+    fn reset_percentiles() {
+        let clock = MockClock::default();
+        let mut reporter = RateReporter::new(Duration::from_millis(100))
+            .with_capacity(100)
+            .with_clock(&clock);
+        reporter.increment();
+        clock.0.set(clock.0.get() + 50_000_000);
+        reporter.increment();
+
+        reporter.reset();
+        assert_eq!(reporter.report_percentiles(), None);
+
+        // first interval after the reset does not span the reset gap
+        clock.0.set(clock.0.get() + 1_000_000_000);
+        reporter.increment();
+        clock.0.set(clock.0.get() + 100_000_000);
+        reporter.increment();
+        let p = reporter.report_percentiles().unwrap();
+        assert_eq!(p.p99, Duration::from_millis(100));
+    }
+
+    #[test]
+    fn jitter() {
+        let clock = MockClock::default();
+        let mut reporter = RateReporter::new(Duration::from_millis(100))
+            .with_jitter()
+            .with_clock(&clock);
+        assert_eq!(reporter.report_with_jitter(), None);
+
+        reporter.increment();
+        for ms in [10, 30, 10, 30, 10, 30] {
+            clock.0.set(clock.0.get() + ms * 1_000_000);
+            reporter.increment();
+        }
+        let (rate, jitter) = reporter.report_with_jitter().unwrap();
+        assert_eq!(rate, 7.0 / 0.12);
+        assert!(jitter.abs_diff(Duration::from_millis(10)) < Duration::from_nanos(10));
+
+        // reset after report
+        assert_eq!(reporter.report_with_jitter(), None);
+        clock.0.set(clock.0.get() + 200_000_000);
+        reporter.increment();
+        let (_, jitter) = reporter.report_with_jitter().unwrap();
+        assert_eq!(jitter, Duration::ZERO);
+    }
+
+    #[test]
+    fn reset_jitter() {
+        let clock = MockClock::default();
+        let mut reporter = RateReporter::new(Duration::from_millis(100))
+            .with_jitter()
+            .with_clock(&clock);
+        reporter.increment();
+        clock.0.set(clock.0.get() + 10_000_000);
+        reporter.increment();
+
+        reporter.reset();
+
+        // neither the stale interval nor the reset gap are included
+        clock.0.set(clock.0.get() + 1_000_000_000);
+        reporter.increment();
+        for _ in 0..2 {
+            clock.0.set(clock.0.get() + 50_000_000);
+            reporter.increment();
+        }
+        let (_, jitter) = reporter.report_with_jitter().unwrap();
+        assert_eq!(jitter, Duration::ZERO);
+    }
+
+    #[test]
+    fn every_zero_elapsed() {
+        let clock = MockClock::default();
+        let mut reporter = RateReporter::new_every(1).with_clock(&clock);
+        assert_eq!(reporter.increment_and_report(), None);
+
+        clock.0.set(clock.0.get() + 500_000_000);
+        assert_eq!(reporter.increment_and_report(), Some(4.0));
+    }
+
+    #[test]
+    fn every_resets() {
+        let mut reporter = RateReporter::new_every(2);
+        for _ in 0..3 {
+            assert!(reporter.increment_and_report().is_none());
+            assert!(reporter.increment_and_report().is_some());
+        }
     }
 }